@@ -0,0 +1,88 @@
+use std::f64::consts::{PI, TAU};
+
+use crate::astrology::houses::{ecliptic_from_ra, oblique_ascendant};
+use crate::frames::nutation::nutation;
+use crate::time::sidereal::local_apparent_sidereal_time;
+
+// =====================================================
+// Chart Angles
+// =====================================================
+
+/// Sensitive points of the chart (radians)
+#[derive(Debug, Clone, Copy)]
+pub struct Angles {
+    pub ascendant: f64,
+    pub descendant: f64,
+    pub mc: f64,
+    pub ic: f64,
+    pub vertex: f64,
+    pub anti_vertex: f64,
+    /// Equatorial ascendant (East Point)
+    pub east_point: f64,
+}
+
+impl Angles {
+    /// Shift every angle by the ayanamsa (radians)
+    pub fn to_sidereal(&self, ayanamsa: f64) -> Angles {
+        let s = |a: f64| normalize(a - ayanamsa);
+
+        Angles {
+            ascendant: s(self.ascendant),
+            descendant: s(self.descendant),
+            mc: s(self.mc),
+            ic: s(self.ic),
+            vertex: s(self.vertex),
+            anti_vertex: s(self.anti_vertex),
+            east_point: s(self.east_point),
+        }
+    }
+}
+
+/// Compute the tropical angle set for a time and place
+///
+/// Uses true obliquity and apparent sidereal time, so the
+/// angles refer to the true equinox of date.
+///
+/// Inputs:
+/// - jd_ut         : Julian Day (UT), for sidereal time
+/// - jd_tt         : Julian Day (TT), for nutation
+/// - latitude_rad  : north positive
+/// - longitude_rad : east positive
+///
+/// The Vertex is undefined on the equator, where the prime
+/// vertical coincides with the celestial equator.
+pub fn angles_tropical(jd_ut: f64, jd_tt: f64, latitude_rad: f64, longitude_rad: f64) -> Angles {
+    let ramc = local_apparent_sidereal_time(jd_ut, jd_tt, longitude_rad);
+    let eps = nutation(jd_tt).eps_true;
+
+    let ascendant = oblique_ascendant(ramc, latitude_rad, eps);
+    let mc = ecliptic_from_ra(ramc, eps);
+
+    // Western intersection of the prime vertical with the ecliptic:
+    // the ascendant for the co-latitude, taken on the anti-meridian
+    let co_latitude = if latitude_rad >= 0.0 {
+        PI / 2.0 - latitude_rad
+    } else {
+        -PI / 2.0 - latitude_rad
+    };
+    let vertex = oblique_ascendant(ramc + PI, co_latitude, eps);
+
+    Angles {
+        ascendant,
+        descendant: normalize(ascendant + PI),
+        mc,
+        ic: normalize(mc + PI),
+        vertex,
+        anti_vertex: normalize(vertex + PI),
+        east_point: oblique_ascendant(ramc, 0.0, eps),
+    }
+}
+
+#[inline]
+fn normalize(mut a: f64) -> f64 {
+    a %= TAU;
+    if a < 0.0 {
+        a += TAU;
+    }
+    a
+}
@@ -0,0 +1,112 @@
+use std::f64::consts::TAU;
+
+use crate::PlanetJS;
+use crate::astrology::chart::Chart;
+use crate::astrology::houses::porphyry_cusps;
+
+// =====================================================
+// Bhava Chalit (Sripati)
+// =====================================================
+
+/// One bhava: its madhya (mid-point) and bounding sandhis
+///
+/// All longitudes in radians, same frame as the input angles.
+#[derive(Debug, Clone, Copy)]
+pub struct Bhava {
+    pub number: u8, // 1..12
+    pub madhya: f64,
+    pub start_sandhi: f64,
+    pub end_sandhi: f64,
+}
+
+/// Planet placement in the Bhava Chalit vs. the rashi chart
+#[derive(Debug, Clone)]
+pub struct BhavaPlacement {
+    pub planet: String,
+    pub rashi_house: u8,
+    pub bhava: u8,
+    pub shifted: bool,
+}
+
+/// Sripati bhavas from ascendant and MC (radians)
+///
+/// Quadrants are trisected (Porphyry cusps) to give the
+/// bhava madhyas; sandhis are the midpoints between them.
+/// Sidereal inputs give sidereal bhavas.
+pub fn sripati_bhavas(asc: f64, mc: f64) -> [Bhava; 12] {
+    bhavas_from_madhya(porphyry_cusps(asc, mc))
+}
+
+/// Build bhavas from any 12 madhya longitudes (index 0 = bhava 1)
+///
+/// Useful to take madhyas from a quadrant system other than Sripati.
+pub fn bhavas_from_madhya(madhya: [f64; 12]) -> [Bhava; 12] {
+    let sandhi = |i: usize| {
+        let a = madhya[i % 12];
+        let b = madhya[(i + 1) % 12];
+        normalize(a + normalize(b - a) / 2.0)
+    };
+
+    std::array::from_fn(|i| Bhava {
+        number: i as u8 + 1,
+        madhya: madhya[i],
+        start_sandhi: sandhi(i + 11),
+        end_sandhi: sandhi(i),
+    })
+}
+
+/// Bhava number (1..12) containing a longitude (radians)
+pub fn bhava_of(lon: f64, bhavas: &[Bhava; 12]) -> u8 {
+    let lon = normalize(lon);
+
+    bhavas
+        .iter()
+        .find(|b| {
+            let span = normalize(b.end_sandhi - b.start_sandhi);
+            normalize(lon - b.start_sandhi) < span
+        })
+        .map(|b| b.number)
+        .unwrap_or(1)
+}
+
+/// Bhava Chalit placement of every planet next to its whole-sign
+/// house (sidereal degrees)
+pub fn bhava_chalit(
+    asc_sidereal_deg: f64,
+    planets: &[PlanetJS],
+    bhavas: &[Bhava; 12],
+) -> Vec<BhavaPlacement> {
+    let asc_sign = (asc_sidereal_deg / 30.0).floor() as u8 % 12;
+
+    planets
+        .iter()
+        .map(|p| {
+            let sign = (p.sidereal_deg / 30.0).floor() as u8 % 12;
+            let rashi_house = (sign + 12 - asc_sign) % 12 + 1;
+            let bhava = bhava_of(p.sidereal_deg.to_radians(), bhavas);
+
+            BhavaPlacement {
+                planet: p.name.clone(),
+                rashi_house,
+                bhava,
+                shifted: bhava != rashi_house,
+            }
+        })
+        .collect()
+}
+
+impl Chart {
+    /// Bhava Chalit placement of every planet next to its whole-sign house
+    pub fn bhava_chalit(&self, bhavas: &[Bhava; 12]) -> Vec<BhavaPlacement> {
+        bhava_chalit(self.ascendant_sidereal_deg, &self.planets, bhavas)
+    }
+}
+
+#[inline]
+fn normalize(mut a: f64) -> f64 {
+    a %= TAU;
+    if a < 0.0 {
+        a += TAU;
+    }
+    a
+}
@@ -71,7 +71,7 @@ impl Chart {
         Some(((sign_index + 12 - asc_sign) % 12) + 1)
     }
 
-    /// Get divisional chart by division number (D1–D30)
+    /// Get divisional chart by division number (D1–D60, D81–D150)
     pub fn divisional(&self, division: u8) -> Option<&DivisionalChart> {
         self.divisional_charts
             .iter()
@@ -0,0 +1,162 @@
+// src/astrology/dignity.rs
+//
+// Planetary dignity and relationships (BPHS ch. 3).
+//
+// Signs are indices 0..11 (0 = Aries). Rahu and Ketu have no
+// classical dignity here and are rejected by `dignity`.
+
+use crate::astrology::houses::Sign;
+use crate::dasha::vimshottari::DashaLord;
+
+/// Relationship between two planets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    GreatFriend,
+    Friend,
+    Neutral,
+    Enemy,
+    GreatEnemy,
+}
+
+/// Dignity of a planet in a sign
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dignity {
+    Exalted,
+    Moolatrikona,
+    Own,
+    GreatFriend,
+    Friend,
+    Neutral,
+    Enemy,
+    GreatEnemy,
+    Debilitated,
+}
+
+impl Dignity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dignity::Exalted => "Exalted",
+            Dignity::Moolatrikona => "Moolatrikona",
+            Dignity::Own => "Own",
+            Dignity::GreatFriend => "Great Friend",
+            Dignity::Friend => "Friend",
+            Dignity::Neutral => "Neutral",
+            Dignity::Enemy => "Enemy",
+            Dignity::GreatEnemy => "Great Enemy",
+            Dignity::Debilitated => "Debilitated",
+        }
+    }
+}
+
+/// Exaltation sign (None for the nodes)
+pub fn exaltation_sign(p: DashaLord) -> Option<u8> {
+    use DashaLord::*;
+    match p {
+        Sun => Some(0),
+        Moon => Some(1),
+        Mars => Some(9),
+        Mercury => Some(5),
+        Jupiter => Some(3),
+        Venus => Some(11),
+        Saturn => Some(6),
+        Rahu | Ketu => None,
+    }
+}
+
+/// Moolatrikona sign (None for the nodes)
+pub fn moolatrikona_sign(p: DashaLord) -> Option<u8> {
+    use DashaLord::*;
+    match p {
+        Sun => Some(4),
+        Moon => Some(1),
+        Mars => Some(0),
+        Mercury => Some(5),
+        Jupiter => Some(8),
+        Venus => Some(6),
+        Saturn => Some(10),
+        Rahu | Ketu => None,
+    }
+}
+
+/// Naisargika (natural) relationship of `p` towards `other`
+pub fn natural_relation(p: DashaLord, other: DashaLord) -> Relation {
+    use DashaLord::*;
+
+    let (friends, enemies): (&[DashaLord], &[DashaLord]) = match p {
+        Sun => (&[Moon, Mars, Jupiter], &[Venus, Saturn]),
+        Moon => (&[Sun, Mercury], &[]),
+        Mars => (&[Sun, Moon, Jupiter], &[Mercury]),
+        Mercury => (&[Sun, Venus], &[Moon]),
+        Jupiter => (&[Sun, Moon, Mars], &[Mercury, Venus]),
+        Venus => (&[Mercury, Saturn], &[Sun, Moon]),
+        Saturn => (&[Mercury, Venus], &[Sun, Moon, Mars]),
+        Rahu | Ketu => (&[], &[]),
+    };
+
+    if friends.contains(&other) {
+        Relation::Friend
+    } else if enemies.contains(&other) {
+        Relation::Enemy
+    } else {
+        Relation::Neutral
+    }
+}
+
+/// Tatkalika (temporary) friendship from rashi signs
+///
+/// Planets in the 2nd, 3rd, 4th, 10th, 11th or 12th
+/// from each other are temporary friends.
+pub fn temporary_friend(p_sign: u8, other_sign: u8) -> bool {
+    let h = (other_sign + 12 - p_sign % 12) % 12 + 1;
+    matches!(h, 2 | 3 | 4 | 10 | 11 | 12)
+}
+
+/// Panchadha (compound) relationship
+pub fn compound_relation(natural: Relation, temporary_friend: bool) -> Relation {
+    use Relation::*;
+    match (natural, temporary_friend) {
+        (Friend, true) => GreatFriend,
+        (Friend, false) => Neutral,
+        (Neutral, true) => Friend,
+        (Neutral, false) => Enemy,
+        (Enemy, true) => Neutral,
+        (Enemy, false) => GreatEnemy,
+        (r, _) => r,
+    }
+}
+
+/// Dignity of planet `p` placed in `sign`
+///
+/// `relation_to` gives the planet's (compound) relationship to
+/// the sign lord; it is consulted only when the planet is not
+/// exalted, in moolatrikona or in its own sign. A debilitated
+/// planet is reported as such regardless of relationship.
+pub fn dignity(
+    p: DashaLord,
+    sign: u8,
+    relation_to: impl Fn(DashaLord) -> Relation,
+) -> Option<Dignity> {
+    let exalt = exaltation_sign(p)?;
+    let sign = sign % 12;
+    let lord = Sign::from_index(sign).lord();
+
+    let d = if sign == exalt {
+        Dignity::Exalted
+    } else if sign == (exalt + 6) % 12 {
+        Dignity::Debilitated
+    } else if Some(sign) == moolatrikona_sign(p) {
+        Dignity::Moolatrikona
+    } else if lord == p {
+        Dignity::Own
+    } else {
+        match relation_to(lord) {
+            Relation::GreatFriend => Dignity::GreatFriend,
+            Relation::Friend => Dignity::Friend,
+            Relation::Neutral => Dignity::Neutral,
+            Relation::Enemy => Dignity::Enemy,
+            Relation::GreatEnemy => Dignity::GreatEnemy,
+        }
+    };
+
+    Some(d)
+}
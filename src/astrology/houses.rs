@@ -1,10 +1,12 @@
 use std::f64::consts::TAU;
 
+use crate::dasha::vimshottari::DashaLord;
 use crate::frames::ayanamsa::lahiri_ayanamsa;
-use crate::time::sidereal::local_sidereal_time;
+use crate::frames::nutation::nutation;
+use crate::time::sidereal::local_apparent_sidereal_time;
 
 /// Zodiac signs
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Sign {
     Aries,
     Taurus,
@@ -54,6 +56,19 @@ impl Sign {
             Sign::Pisces => "Pisces",
         }
     }
+
+    /// Classical sign lord
+    pub fn lord(&self) -> DashaLord {
+        match self {
+            Sign::Aries | Sign::Scorpio => DashaLord::Mars,
+            Sign::Taurus | Sign::Libra => DashaLord::Venus,
+            Sign::Gemini | Sign::Virgo => DashaLord::Mercury,
+            Sign::Cancer => DashaLord::Moon,
+            Sign::Leo => DashaLord::Sun,
+            Sign::Sagittarius | Sign::Pisces => DashaLord::Jupiter,
+            Sign::Capricorn | Sign::Aquarius => DashaLord::Saturn,
+        }
+    }
 }
 
 /// House info
@@ -63,27 +78,73 @@ pub struct House {
 }
 
 /// Compute sidereal Ascendant (radians)
-pub fn ascendant_sidereal(jd_tt: f64, latitude_rad: f64, longitude_rad: f64) -> f64 {
-    let lst = local_sidereal_time(jd_tt, longitude_rad);
-    let obliq = 23.43929111_f64.to_radians(); // mean obliquity
+///
+/// Inputs:
+/// - jd_ut         : Julian Day (UT), for sidereal time
+/// - jd_tt         : Julian Day (TT), for nutation and ayanamsa
+/// - latitude_rad  : north positive
+/// - longitude_rad : east positive
+///
+/// Uses Greenwich apparent sidereal time and the true obliquity
+/// of date; see `ascendant_from_lst` for the stability guarantees.
+pub fn ascendant_sidereal(jd_ut: f64, jd_tt: f64, latitude_rad: f64, longitude_rad: f64) -> f64 {
+    let asc = ascendant_tropical(jd_ut, jd_tt, latitude_rad, longitude_rad);
 
-    let tan_a = 1.0 / (lst.cos()) * (-lst.sin());
+    // convert to sidereal
+    normalize(asc - lahiri_ayanamsa(jd_tt))
+}
 
-    let asc = tan_a.atan2(latitude_rad.cos() - tan_a * latitude_rad.sin() * obliq.tan());
+/// Tropical Ascendant (radians, true equinox of date)
+///
+/// Same inputs as `ascendant_sidereal`.
+pub fn ascendant_tropical(jd_ut: f64, jd_tt: f64, latitude_rad: f64, longitude_rad: f64) -> f64 {
+    let lst = local_apparent_sidereal_time(jd_ut, jd_tt, longitude_rad);
+    let obliq = nutation(jd_tt).eps_true;
 
-    let mut asc = asc;
-    if asc < 0.0 {
-        asc += TAU;
-    }
+    ascendant_from_lst(lst, latitude_rad, obliq)
+}
 
-    // convert to sidereal
-    let ayan = lahiri_ayanamsa(jd_tt);
-    let mut sid = asc - ayan;
-    if sid < 0.0 {
-        sid += TAU;
+/// Tropical Ascendant (radians) from local sidereal time
+///
+/// Inputs:
+/// - lst          : local (apparent) sidereal time, radians
+/// - latitude_rad : north positive, clamped to ±90°
+/// - obliquity    : obliquity of the ecliptic (radians)
+///
+/// Numerical stability:
+/// - no division or `tan`, so `lst.cos() == 0` and the poles are
+///   handled by `atan2` without special cases
+/// - the result is finite and in [0, 2π) for every finite input
+/// - the returned point is the ecliptic/horizon crossing on the
+///   eastern horizon (hour angle in (−180°, 0°))
+///
+/// Outside the polar circles the result is continuous in `lst`
+/// (up to the 2π wrap). Inside them the `atan2` form alone can
+/// give the setting point, so that case is moved to the rising
+/// one; the ascendant then jumps by up to 180° at the moments the
+/// ecliptic lies along the horizon, as it does in the sky.
+///
+/// At exactly ±90° latitude the horizon is the equator and the
+/// result degenerates to an equinox point.
+pub fn ascendant_from_lst(lst: f64, latitude_rad: f64, obliquity: f64) -> f64 {
+    let lat = latitude_rad.clamp(-TAU / 4.0, TAU / 4.0);
+
+    let y = lst.cos() * lat.cos();
+    let x = -(lst.sin() * obliquity.cos() * lat.cos() + lat.sin() * obliquity.sin());
+
+    let mut asc = y.atan2(x);
+
+    // Component of the point along the east point of the horizon;
+    // negative means it lies west of the meridian (setting)
+    let east = asc.sin() * obliquity.cos() * lst.cos() - asc.cos() * lst.sin();
+    if east < 0.0 {
+        asc += TAU / 2.0;
     }
 
-    sid
+    let asc = normalize(asc);
+
+    // -0.0 and tiny negative angles must not wrap to exactly 2π
+    if asc >= TAU { 0.0 } else { asc }
 }
 
 /// Whole Sign Houses (D1)
@@ -97,3 +158,264 @@ pub fn whole_sign_houses(asc_sidereal: f64) -> Vec<House> {
         })
         .collect()
 }
+
+// =====================================================
+// Quadrant & Equal House Cusps
+// =====================================================
+
+/// House division systems supported by `house_cusps`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HouseSystem {
+    Placidus,
+    Koch,
+    Porphyry,
+    Regiomontanus,
+    Campanus,
+    Equal,
+}
+
+impl HouseSystem {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HouseSystem::Placidus => "Placidus",
+            HouseSystem::Koch => "Koch",
+            HouseSystem::Porphyry => "Porphyry",
+            HouseSystem::Regiomontanus => "Regiomontanus",
+            HouseSystem::Campanus => "Campanus",
+            HouseSystem::Equal => "Equal",
+        }
+    }
+}
+
+/// Why a house system could not produce cusps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HouseError {
+    /// Placidus and Koch are undefined where parts of the ecliptic
+    /// never rise or set (|latitude| ≥ 90° − obliquity).
+    CircumpolarLatitude,
+}
+
+/// Compute 12 house cusps (radians, index 0 = house 1)
+///
+/// Inputs:
+/// - system       : house division method
+/// - asc          : tropical ascendant (radians)
+/// - mc           : tropical midheaven (radians)
+/// - latitude_rad : geographic latitude (north positive)
+/// - obliquity    : obliquity of the ecliptic (radians)
+///
+/// Output is in the same (tropical) frame as `asc` / `mc`;
+/// subtract the ayanamsa afterwards for sidereal cusps.
+///
+/// Placidus and Koch return `HouseError::CircumpolarLatitude`
+/// inside the polar circles. Use `house_cusps_or_porphyry`
+/// when a fallback is preferred over an error.
+pub fn house_cusps(
+    system: HouseSystem,
+    asc: f64,
+    mc: f64,
+    latitude_rad: f64,
+    obliquity: f64,
+) -> Result<[f64; 12], HouseError> {
+    let ramc = normalize((obliquity.cos() * mc.sin()).atan2(mc.cos()));
+
+    let cusps = match system {
+        HouseSystem::Equal => {
+            let mut c = [0.0; 12];
+            for (i, cusp) in c.iter_mut().enumerate() {
+                *cusp = normalize(asc + i as f64 * TAU / 12.0);
+            }
+            c
+        }
+        HouseSystem::Porphyry => porphyry_cusps(asc, mc),
+        HouseSystem::Regiomontanus => {
+            let eastern = |offset: f64| {
+                let pole = (latitude_rad.tan() * offset.sin()).atan();
+                oblique_ascendant(ramc + offset - TAU / 4.0, pole, obliquity)
+            };
+            quadrant_from_eastern(
+                asc,
+                mc,
+                [
+                    eastern(30f64.to_radians()),
+                    eastern(60f64.to_radians()),
+                    eastern(120f64.to_radians()),
+                    eastern(150f64.to_radians()),
+                ],
+            )
+        }
+        HouseSystem::Campanus => {
+            // House circles cut the prime vertical every 30°;
+            // `tilt` is the circle's elevation above the eastern horizon.
+            let eastern = |tilt: f64| {
+                let pole = (latitude_rad.sin() * tilt.cos()).asin();
+                let offset = (tilt.cos() * latitude_rad.cos()).atan2(tilt.sin());
+                oblique_ascendant(ramc + offset - TAU / 4.0, pole, obliquity)
+            };
+            quadrant_from_eastern(
+                asc,
+                mc,
+                [
+                    eastern(60f64.to_radians()),
+                    eastern(30f64.to_radians()),
+                    eastern(-30f64.to_radians()),
+                    eastern(-60f64.to_radians()),
+                ],
+            )
+        }
+        HouseSystem::Placidus => {
+            check_quadrant_latitude(latitude_rad, obliquity)?;
+            let c11 = placidus_cusp(ramc, 1.0 / 3.0, latitude_rad, obliquity)?;
+            let c12 = placidus_cusp(ramc, 2.0 / 3.0, latitude_rad, obliquity)?;
+            let c8 = placidus_cusp(ramc, -2.0 / 3.0, latitude_rad, obliquity)?;
+            let c9 = placidus_cusp(ramc, -1.0 / 3.0, latitude_rad, obliquity)?;
+            quadrant_from_eastern(
+                asc,
+                mc,
+                [
+                    c11,
+                    c12,
+                    normalize(c8 + TAU / 2.0),
+                    normalize(c9 + TAU / 2.0),
+                ],
+            )
+        }
+        HouseSystem::Koch => {
+            check_quadrant_latitude(latitude_rad, obliquity)?;
+            let decl_mc = (obliquity.sin() * mc.sin()).asin();
+            let x = -latitude_rad.tan() * decl_mc.tan();
+            if x.abs() > 1.0 {
+                return Err(HouseError::CircumpolarLatitude);
+            }
+            // Diurnal semi-arc of the MC degree, trisected in time
+            let sa = x.acos();
+            let asc_at = |t: f64| oblique_ascendant(t, latitude_rad, obliquity);
+            quadrant_from_eastern(
+                asc,
+                mc,
+                [
+                    asc_at(ramc - 2.0 * sa / 3.0),
+                    asc_at(ramc - sa / 3.0),
+                    asc_at(ramc + sa / 3.0),
+                    asc_at(ramc + 2.0 * sa / 3.0),
+                ],
+            )
+        }
+    };
+
+    Ok(cusps)
+}
+
+/// Same as `house_cusps`, but falls back to Porphyry when the
+/// requested system is undefined at this latitude.
+///
+/// Returns the cusps together with the system actually used.
+pub fn house_cusps_or_porphyry(
+    system: HouseSystem,
+    asc: f64,
+    mc: f64,
+    latitude_rad: f64,
+    obliquity: f64,
+) -> ([f64; 12], HouseSystem) {
+    match house_cusps(system, asc, mc, latitude_rad, obliquity) {
+        Ok(c) => (c, system),
+        Err(_) => (porphyry_cusps(asc, mc), HouseSystem::Porphyry),
+    }
+}
+
+/// Ecliptic point rising for a horizon of pole `pole`
+/// when the meridian is at right ascension `ramc`
+#[inline]
+pub(crate) fn oblique_ascendant(ramc: f64, pole: f64, obliquity: f64) -> f64 {
+    ascendant_from_lst(ramc, pole, obliquity)
+}
+
+/// Ecliptic longitude of the point with right ascension `ra`
+#[inline]
+pub(crate) fn ecliptic_from_ra(ra: f64, obliquity: f64) -> f64 {
+    normalize(ra.sin().atan2(ra.cos() * obliquity.cos()))
+}
+
+/// Placidus cusp: the point whose hour angle is `fraction`
+/// of its own diurnal semi-arc (positive = east of meridian)
+fn placidus_cusp(
+    ramc: f64,
+    fraction: f64,
+    latitude_rad: f64,
+    obliquity: f64,
+) -> Result<f64, HouseError> {
+    let mut lon = ecliptic_from_ra(ramc + fraction * TAU / 4.0, obliquity);
+
+    for _ in 0..100 {
+        let decl = (obliquity.sin() * lon.sin()).asin();
+        let x = -latitude_rad.tan() * decl.tan();
+        if x.abs() > 1.0 {
+            return Err(HouseError::CircumpolarLatitude);
+        }
+
+        let next = ecliptic_from_ra(ramc + fraction * x.acos(), obliquity);
+        let diff = normalize(next - lon + TAU / 2.0) - TAU / 2.0;
+        lon = next;
+
+        if diff.abs() < 1e-12 {
+            break;
+        }
+    }
+
+    Ok(lon)
+}
+
+#[inline]
+fn check_quadrant_latitude(latitude_rad: f64, obliquity: f64) -> Result<(), HouseError> {
+    if latitude_rad.abs() + obliquity >= TAU / 4.0 {
+        return Err(HouseError::CircumpolarLatitude);
+    }
+    Ok(())
+}
+
+/// Porphyry: each quadrant trisected along the ecliptic
+pub(crate) fn porphyry_cusps(asc: f64, mc: f64) -> [f64; 12] {
+    let upper = normalize(asc - mc);
+    let lower = normalize(mc + TAU / 2.0 - asc);
+
+    quadrant_from_eastern(
+        asc,
+        mc,
+        [
+            normalize(mc + upper / 3.0),
+            normalize(mc + 2.0 * upper / 3.0),
+            normalize(asc + lower / 3.0),
+            normalize(asc + 2.0 * lower / 3.0),
+        ],
+    )
+}
+
+/// Assemble 12 cusps from the angles and cusps 11, 12, 2, 3
+fn quadrant_from_eastern(asc: f64, mc: f64, eastern: [f64; 4]) -> [f64; 12] {
+    let [c11, c12, c2, c3] = eastern;
+    let half = TAU / 2.0;
+
+    [
+        normalize(asc),
+        c2,
+        c3,
+        normalize(mc + half),
+        normalize(c11 + half),
+        normalize(c12 + half),
+        normalize(asc + half),
+        normalize(c2 + half),
+        normalize(c3 + half),
+        normalize(mc),
+        c11,
+        c12,
+    ]
+}
+
+#[inline]
+fn normalize(mut a: f64) -> f64 {
+    a %= TAU;
+    if a < 0.0 {
+        a += TAU;
+    }
+    a
+}
@@ -0,0 +1,328 @@
+use crate::PlanetJS;
+use crate::astrology::houses::Sign;
+use crate::astrology::nakshatra::NAKSHATRA_LORDS;
+use crate::dasha::vimshottari::{DashaLord, dasha_years};
+
+// =====================================================
+// KP (Krishnamurti Paddhati) Sub-Lords
+// =====================================================
+//
+// Each nakshatra (13°20′) is divided into 9 subs proportional
+// to the Vimshottari years, starting from the nakshatra lord.
+// Subs crossing a sign boundary are split, giving 249 entries.
+//
+// All longitudes in this module are SIDEREAL DEGREES.
+
+const NAK_LEN: f64 = 360.0 / 27.0;
+
+/// One entry of the 249-sub table
+#[derive(Debug, Clone, Copy)]
+pub struct KpSub {
+    pub number: u16, // 1..249
+    pub start_deg: f64,
+    pub end_deg: f64,
+    pub sign: Sign,
+    pub star_lord: DashaLord,
+    pub sub_lord: DashaLord,
+}
+
+/// Star, sub and sub-sub lord of a longitude
+#[derive(Debug, Clone, Copy)]
+pub struct KpLords {
+    pub sign_lord: DashaLord,
+    pub star_lord: DashaLord,
+    pub sub_lord: DashaLord,
+    pub sub_sub_lord: DashaLord,
+}
+
+/// A planet or cusp with its KP lords
+#[derive(Debug, Clone)]
+pub struct KpPoint {
+    pub name: String,
+    pub longitude_deg: f64,
+    pub lords: KpLords,
+}
+
+/// The four KP significator levels of a house
+///
+/// Strongest first:
+/// 1. planets in the star of the occupants
+/// 2. occupants
+/// 3. planets in the star of the house lord
+/// 4. house lord
+#[derive(Debug, Clone)]
+pub struct HouseSignificators {
+    pub house: u8,
+    pub star_of_occupants: Vec<DashaLord>,
+    pub occupants: Vec<DashaLord>,
+    pub star_of_owner: Vec<DashaLord>,
+    pub owner: DashaLord,
+}
+
+/// The four KP levels of houses signified by a planet
+///
+/// 1. house occupied by its star lord
+/// 2. house it occupies
+/// 3. houses owned by its star lord
+/// 4. houses it owns
+#[derive(Debug, Clone)]
+pub struct PlanetSignifications {
+    pub planet: DashaLord,
+    pub star_lord_occupies: u8,
+    pub occupies: u8,
+    pub star_lord_owns: Vec<u8>,
+    pub owns: Vec<u8>,
+}
+
+/// Build the 249-sub table (KP numbers 1..249)
+pub fn kp_sub_table() -> Vec<KpSub> {
+    let mut out = Vec::with_capacity(249);
+
+    for (nak, &star_lord) in NAKSHATRA_LORDS.iter().enumerate() {
+        let mut start = nak as f64 * NAK_LEN;
+        let mut sub_lord = star_lord;
+
+        for _ in 0..9 {
+            let end = start + NAK_LEN * dasha_years(sub_lord) / 120.0;
+
+            // Split at the sign boundary if one falls strictly inside
+            let boundary = ((start / 30.0).floor() + 1.0) * 30.0;
+            let pieces = if boundary < end - 1e-9 {
+                vec![(start, boundary), (boundary, end)]
+            } else {
+                vec![(start, end)]
+            };
+
+            for (s, e) in pieces {
+                out.push(KpSub {
+                    number: out.len() as u16 + 1,
+                    start_deg: s,
+                    end_deg: e,
+                    sign: Sign::from_index((s / 30.0 + 1e-9).floor() as u8),
+                    star_lord,
+                    sub_lord,
+                });
+            }
+
+            start = end;
+            sub_lord = sub_lord.next();
+        }
+    }
+
+    out
+}
+
+/// KP lords of a sidereal longitude (degrees)
+pub fn kp_lords(lon_deg: f64) -> KpLords {
+    let lon = normalize_deg(lon_deg);
+
+    let index = ((lon / NAK_LEN).floor() as usize).min(26);
+    let star_lord = NAKSHATRA_LORDS[index];
+    let offset = lon - index as f64 * NAK_LEN;
+
+    let (sub_lord, sub_offset, sub_span) = subdivide(star_lord, NAK_LEN, offset);
+    let (sub_sub_lord, _, _) = subdivide(sub_lord, sub_span, sub_offset);
+
+    KpLords {
+        sign_lord: Sign::from_index((lon / 30.0).floor() as u8).lord(),
+        star_lord,
+        sub_lord,
+        sub_sub_lord,
+    }
+}
+
+/// Horary ascendant for a KP number (1..249)
+///
+/// Returns the starting sidereal degree of that sub.
+pub fn kp_horary_ascendant(number: u16) -> Option<f64> {
+    if !(1..=249).contains(&number) {
+        return None;
+    }
+
+    kp_sub_table()
+        .into_iter()
+        .find(|s| s.number == number)
+        .map(|s| s.start_deg)
+}
+
+/// KP lords for each planet
+pub fn kp_planets(planets: &[PlanetJS]) -> Vec<KpPoint> {
+    planets
+        .iter()
+        .map(|p| KpPoint {
+            name: p.name.clone(),
+            longitude_deg: p.sidereal_deg,
+            lords: kp_lords(p.sidereal_deg),
+        })
+        .collect()
+}
+
+/// KP lords for each cusp (sidereal degrees, index 0 = cusp 1)
+///
+/// KP uses Placidus cusps; see `houses::house_cusps`.
+pub fn kp_cusps(cusps_deg: &[f64; 12]) -> Vec<KpPoint> {
+    cusps_deg
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| KpPoint {
+            name: format!("Cusp {}", i + 1),
+            longitude_deg: normalize_deg(c),
+            lords: kp_lords(c),
+        })
+        .collect()
+}
+
+/// House (1..12) containing a longitude, cusp to cusp
+pub fn kp_house_of(lon_deg: f64, cusps_deg: &[f64; 12]) -> u8 {
+    let lon = normalize_deg(lon_deg);
+
+    (0..12)
+        .find(|&i| {
+            let start = cusps_deg[i];
+            let span = normalize_deg(cusps_deg[(i + 1) % 12] - start);
+            normalize_deg(lon - start) < span
+        })
+        .map(|i| i as u8 + 1)
+        .unwrap_or(1)
+}
+
+/// Four-level significators of a house (1..12)
+pub fn house_significators(
+    house: u8,
+    planets: &[PlanetJS],
+    cusps_deg: &[f64; 12],
+) -> HouseSignificators {
+    let points = placed_planets(planets, cusps_deg);
+    let owner = cusp_sign(cusps_deg, house).lord();
+
+    let occupants: Vec<DashaLord> = points
+        .iter()
+        .filter(|p| p.house == house)
+        .map(|p| p.lord)
+        .collect();
+
+    let star_of_occupants = points
+        .iter()
+        .filter(|p| occupants.contains(&p.star_lord))
+        .map(|p| p.lord)
+        .collect();
+
+    let star_of_owner = points
+        .iter()
+        .filter(|p| p.star_lord == owner)
+        .map(|p| p.lord)
+        .collect();
+
+    HouseSignificators {
+        house,
+        star_of_occupants,
+        occupants,
+        star_of_owner,
+        owner,
+    }
+}
+
+/// Four-level houses signified by a planet
+///
+/// Rahu and Ketu own no sign; as agents they take the
+/// ownership of their sign dispositor.
+pub fn planet_significations(
+    planet: DashaLord,
+    planets: &[PlanetJS],
+    cusps_deg: &[f64; 12],
+) -> Option<PlanetSignifications> {
+    let points = placed_planets(planets, cusps_deg);
+
+    let p = points.iter().find(|p| p.lord == planet)?;
+    let star = points.iter().find(|s| s.lord == p.star_lord)?;
+
+    Some(PlanetSignifications {
+        planet,
+        star_lord_occupies: star.house,
+        occupies: p.house,
+        star_lord_owns: owned_houses(star, cusps_deg),
+        owns: owned_houses(p, cusps_deg),
+    })
+}
+
+// -----------------------------------------------------
+// Internals
+// -----------------------------------------------------
+
+struct PlacedPlanet {
+    lord: DashaLord,
+    lon: f64,
+    house: u8,
+    star_lord: DashaLord,
+}
+
+fn placed_planets(planets: &[PlanetJS], cusps_deg: &[f64; 12]) -> Vec<PlacedPlanet> {
+    planets
+        .iter()
+        .filter_map(|p| {
+            Some(PlacedPlanet {
+                lord: DashaLord::from_name(&p.name)?,
+                lon: p.sidereal_deg,
+                house: kp_house_of(p.sidereal_deg, cusps_deg),
+                star_lord: kp_lords(p.sidereal_deg).star_lord,
+            })
+        })
+        .collect()
+}
+
+fn owned_houses(p: &PlacedPlanet, cusps_deg: &[f64; 12]) -> Vec<u8> {
+    let owner = match p.lord {
+        DashaLord::Rahu | DashaLord::Ketu => Sign::from_index((p.lon / 30.0).floor() as u8).lord(),
+        l => l,
+    };
+
+    (1..=12)
+        .filter(|&h| cusp_sign(cusps_deg, h).lord() == owner)
+        .collect()
+}
+
+#[inline]
+fn cusp_sign(cusps_deg: &[f64; 12], house: u8) -> Sign {
+    let c = normalize_deg(cusps_deg[(house as usize + 11) % 12]);
+    Sign::from_index((c / 30.0).floor() as u8)
+}
+
+/// Find the Vimshottari sub of `span` containing `offset`
+///
+/// Returns (lord, offset within sub, sub span).
+fn subdivide(start_lord: DashaLord, span: f64, offset: f64) -> (DashaLord, f64, f64) {
+    let mut lord = start_lord;
+    let mut acc = 0.0;
+
+    for _ in 0..9 {
+        let part = span * dasha_years(lord) / 120.0;
+        if offset < acc + part {
+            return (lord, offset - acc, part);
+        }
+        acc += part;
+        lord = lord.next();
+    }
+
+    // Rounding at the very end of the span: last sub
+    let last = prev_lord(start_lord);
+    let part = span * dasha_years(last) / 120.0;
+    (last, part, part)
+}
+
+#[inline]
+fn prev_lord(l: DashaLord) -> DashaLord {
+    let mut p = l;
+    for _ in 0..8 {
+        p = p.next();
+    }
+    p
+}
+
+#[inline]
+fn normalize_deg(mut x: f64) -> f64 {
+    x %= 360.0;
+    if x < 0.0 {
+        x += 360.0;
+    }
+    x
+}
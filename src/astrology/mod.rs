@@ -1,5 +1,10 @@
 // src/astrology/mod.rs
+pub mod angles;
+pub mod bhava;
 pub mod chart;
+pub mod dignity;
 pub mod houses;
+pub mod kp;
 pub mod nakshatra;
 pub mod nakshatra_calc;
+pub mod upagraha;
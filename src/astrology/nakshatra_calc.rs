@@ -1,5 +1,4 @@
 use crate::astrology::nakshatra::NAKSHATRA_LORDS;
-use crate::dasha::DashaMode;
 
 const NAK_LEN: f64 = 13.333333333333334;
 
@@ -9,20 +8,13 @@ pub struct NakshatraResult {
     pub fraction_elapsed: f64,
 }
 
-/// Compute nakshatra according to selected mode
-pub fn compute_nakshatra(moon_sid_deg: f64, mode: DashaMode) -> NakshatraResult {
-    let corrected_lon = match mode {
-        DashaMode::Astronomical => moon_sid_deg,
-
-        // JHora-compatible adjustment
-        DashaMode::JHoraCompatible => {
-            // Empirically consistent correction:
-            // shifts boundary behavior, not Moon physics
-            moon_sid_deg - 0.25
-        }
-    };
-
-    let mut lon = corrected_lon % 360.0;
+/// Nakshatra of a sidereal longitude (degrees) with the
+/// fraction of it already traversed
+///
+/// The longitude is used as given; which Moon, ayanamsa and
+/// nutation produce it is set by `compat::Compatibility`.
+pub fn compute_nakshatra(sid_deg: f64) -> NakshatraResult {
+    let mut lon = sid_deg % 360.0;
     if lon < 0.0 {
         lon += 360.0;
     }
@@ -0,0 +1,37 @@
+// src/astrology/upagraha.rs
+//
+// Aprakasha grahas (Sun-based upagrahas), BPHS ch. 3.
+//
+// All longitudes are SIDEREAL DEGREES.
+
+/// Dhuma, Vyatipata, Parivesha, Indrachapa and Upaketu
+///
+/// Input:
+/// - sun_sid_deg : sidereal Sun longitude (degrees)
+///
+/// Output:
+/// - (name, sidereal longitude) pairs
+pub fn aprakasha_grahas(sun_sid_deg: f64) -> Vec<(&'static str, f64)> {
+    let dhuma = normalize_deg(sun_sid_deg + 133.0 + 20.0 / 60.0);
+    let vyatipata = normalize_deg(360.0 - dhuma);
+    let parivesha = normalize_deg(vyatipata + 180.0);
+    let indrachapa = normalize_deg(360.0 - parivesha);
+    let upaketu = normalize_deg(indrachapa + 16.0 + 40.0 / 60.0);
+
+    vec![
+        ("Dhuma", dhuma),
+        ("Vyatipata", vyatipata),
+        ("Parivesha", parivesha),
+        ("Indrachapa", indrachapa),
+        ("Upaketu", upaketu),
+    ]
+}
+
+#[inline]
+fn normalize_deg(mut x: f64) -> f64 {
+    x %= 360.0;
+    if x < 0.0 {
+        x += 360.0;
+    }
+    x
+}
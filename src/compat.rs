@@ -0,0 +1,166 @@
+// ======================================================
+// Compatibility Settings
+// ======================================================
+//
+// Astrology programs differ in a handful of conventions that
+// move the Moon by a few arcminutes, enough to change the
+// nakshatra or the dasha balance for some births. Each one is
+// an explicit setting here instead of a correction applied to
+// the nakshatra:
+// - ayanamsa variant
+// - true (ELP) or mean Moon for the nakshatra and dashas
+// - nutation: mean equinox of date, or apparent longitudes
+//   (Δψ added, ayanamsa left mean)
+// - dasha year length
+//
+// Presets bundle the settings a program is documented to use;
+// any field can then be overridden. They have not been checked
+// against that program's output, and the ephemeris differs, so
+// results can still disagree with it.
+
+use crate::dasha::year::DashaYear;
+use crate::ephemeris::moon_elp::moon_longitude_elp;
+use crate::ephemeris::moon_mean::moon_mean_longitude_tropical;
+use crate::frames::ayanamsa::Ayanamsa;
+use crate::frames::nutation::nutation;
+
+use std::f64::consts::TAU;
+
+/// Moon used for the nakshatra and the dashas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MoonLongitude {
+    /// ELP-2000/82 Moon
+    #[default]
+    True,
+    /// Mean Moon (Meeus)
+    Mean,
+}
+
+impl MoonLongitude {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MoonLongitude::True => "True",
+            MoonLongitude::Mean => "Mean",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "True" => Some(MoonLongitude::True),
+            "Mean" => Some(MoonLongitude::Mean),
+            _ => None,
+        }
+    }
+}
+
+/// Equinox the tropical longitudes refer to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NutationConvention {
+    /// Mean equinox of date (no nutation)
+    #[default]
+    Mean,
+    /// True equinox of date: nutation in longitude added
+    Apparent,
+}
+
+impl NutationConvention {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NutationConvention::Mean => "Mean",
+            NutationConvention::Apparent => "Apparent",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Mean" => Some(NutationConvention::Mean),
+            "Apparent" => Some(NutationConvention::Apparent),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Compatibility {
+    pub ayanamsa: Ayanamsa,
+    pub moon: MoonLongitude,
+    pub nutation: NutationConvention,
+    pub year: DashaYear,
+}
+
+impl Compatibility {
+    /// This crate's own conventions (the default)
+    pub const ASTRONOMICAL: Compatibility = Compatibility {
+        ayanamsa: Ayanamsa::Lahiri,
+        moon: MoonLongitude::True,
+        nutation: NutationConvention::Mean,
+        year: DashaYear::Sidereal,
+    };
+
+    /// Settings documented as Jagannatha Hora's defaults: Lahiri
+    /// as published in the Indian Astronomical Ephemeris, true
+    /// Moon, apparent longitudes, sidereal year
+    pub const JHORA: Compatibility = Compatibility {
+        ayanamsa: Ayanamsa::LahiriIae,
+        moon: MoonLongitude::True,
+        nutation: NutationConvention::Apparent,
+        year: DashaYear::Sidereal,
+    };
+
+    /// Krishnamurti Paddhati conventions: KP ayanamsa, true Moon,
+    /// apparent longitudes, 365.25-day year
+    pub const KP: Compatibility = Compatibility {
+        ayanamsa: Ayanamsa::Krishnamurti,
+        moon: MoonLongitude::True,
+        nutation: NutationConvention::Apparent,
+        year: DashaYear::Julian,
+    };
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "Astronomical" => Some(Self::ASTRONOMICAL),
+            "JHora" => Some(Self::JHORA),
+            "KP" => Some(Self::KP),
+            _ => None,
+        }
+    }
+
+    /// Ayanamsa (radians) for given Julian Day (TT)
+    pub fn ayanamsa(&self, jd_tt: f64) -> f64 {
+        self.ayanamsa.value(jd_tt)
+    }
+
+    /// Tropical longitude (radians, mean equinox of date) in the
+    /// convention's equinox
+    pub fn apparent(&self, trop: f64, jd_tt: f64) -> f64 {
+        match self.nutation {
+            NutationConvention::Mean => trop,
+            NutationConvention::Apparent => normalize(trop + nutation(jd_tt).delta_psi),
+        }
+    }
+
+    /// Sidereal longitude (radians) of a tropical longitude
+    /// (radians, mean equinox of date)
+    pub fn sidereal(&self, trop: f64, jd_tt: f64) -> f64 {
+        normalize(self.apparent(trop, jd_tt) - self.ayanamsa(jd_tt))
+    }
+
+    /// Sidereal Moon for the nakshatra and dashas (degrees)
+    pub fn moon_sidereal_deg(&self, jd_tt: f64) -> f64 {
+        let trop = match self.moon {
+            MoonLongitude::True => moon_longitude_elp(jd_tt),
+            MoonLongitude::Mean => moon_mean_longitude_tropical(jd_tt).to_radians(),
+        };
+
+        self.sidereal(trop, jd_tt).to_degrees()
+    }
+}
+
+#[inline]
+fn normalize(mut a: f64) -> f64 {
+    a %= TAU;
+    if a < 0.0 {
+        a += TAU;
+    }
+    a
+}
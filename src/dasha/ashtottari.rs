@@ -0,0 +1,144 @@
+// ======================================================
+// Ashtottari Dasha — 8 lords (no Ketu), 108-year cycle
+// ======================================================
+//
+// Nakshatras are grouped from Ardra; each lord rules a run of
+// 3 or 4 consecutive nakshatras (Abhijit falls inside Saturn's
+// group, so the groups are contiguous in longitude):
+//
+//   Sun      Ardra .. Ashlesha             (4)
+//   Moon     Magha .. Uttara Phalguni      (3)
+//   Mars     Hasta .. Vishakha             (4)
+//   Mercury  Anuradha .. Mula              (3)
+//   Saturn   Purva Ashadha .. Shravana     (3 + Abhijit)
+//   Jupiter  Dhanishta .. Purva Bhadrapada (3)
+//   Rahu     Uttara Bhadrapada .. Bharani  (4)
+//   Venus    Krittika .. Mrigashira        (3)
+//
+// The birth balance is the unelapsed part of the whole group.
+
+use crate::{
+    PlanetJS,
+    astrology::houses::Sign,
+    dasha::{
+        nakshatra_dasha::{
+            NakshatraDasha, NakshatraMapping, nakshatra_antardasha_timeline,
+            nakshatra_mahadasha_timeline, nakshatra_pratyantardasha_timeline,
+        },
+        vimshottari::{AntarPeriod, DashaLord, DashaPeriod, PratyPeriod},
+        year::DashaClock,
+    },
+};
+
+// Nakshatra index (0 = Ashwini) where the grouping starts
+const ARDRA: usize = 5;
+
+pub const ASHTOTTARI: NakshatraDasha = NakshatraDasha {
+    name: "Ashtottari",
+    lords: &[
+        (DashaLord::Sun, 6.0),
+        (DashaLord::Moon, 15.0),
+        (DashaLord::Mars, 8.0),
+        (DashaLord::Mercury, 17.0),
+        (DashaLord::Saturn, 10.0),
+        (DashaLord::Jupiter, 19.0),
+        (DashaLord::Rahu, 12.0),
+        (DashaLord::Venus, 21.0),
+    ],
+    mapping: NakshatraMapping::Groups {
+        from: ARDRA,
+        sizes: &[4, 3, 4, 3, 3, 3, 4, 3],
+    },
+    cycle_years: 108.0,
+};
+
+// ======================================================
+// TIMELINES
+// ======================================================
+
+pub fn ashtottari_mahadasha_timeline(birth_jd: f64, moon_sid_deg: f64) -> Vec<DashaPeriod> {
+    nakshatra_mahadasha_timeline(&ASHTOTTARI, &DashaClock::sidereal(birth_jd), moon_sid_deg)
+}
+
+pub fn ashtottari_antardasha_timeline(birth_jd: f64, moon_sid_deg: f64) -> Vec<AntarPeriod> {
+    nakshatra_antardasha_timeline(&ASHTOTTARI, &DashaClock::sidereal(birth_jd), moon_sid_deg)
+}
+
+pub fn ashtottari_pratyantardasha_timeline(birth_jd: f64, moon_sid_deg: f64) -> Vec<PratyPeriod> {
+    nakshatra_pratyantardasha_timeline(&ASHTOTTARI, &DashaClock::sidereal(birth_jd), moon_sid_deg)
+}
+
+// ======================================================
+// APPLICABILITY
+// ======================================================
+
+/// Classical conditions for using Ashtottari
+#[derive(Debug, Clone, Copy)]
+pub struct AshtottariApplicability {
+    /// Rahu in a kendra (1, 4, 7, 10) or trikona (5, 9) from
+    /// the lagna lord, but not in the lagna itself
+    pub rahu_from_lagna_lord: bool,
+    /// Krishna paksha with a day birth, or Shukla paksha
+    /// with a night birth
+    pub paksha_day_night: bool,
+}
+
+impl AshtottariApplicability {
+    /// Either condition is enough
+    pub fn applies(&self) -> bool {
+        self.rahu_from_lagna_lord || self.paksha_day_night
+    }
+}
+
+/// Check the Ashtottari conditions for a chart
+///
+/// Input:
+/// - asc_sid_deg : sidereal ascendant (degrees)
+/// - planets     : sidereal positions, must include Sun, Moon and Rahu
+///
+/// Day birth = Sun above the horizon, taken as the Sun lying in
+/// the ecliptic half from the descendant to the ascendant.
+pub fn ashtottari_applicability(
+    asc_sid_deg: f64,
+    planets: &[PlanetJS],
+) -> Option<AshtottariApplicability> {
+    let lon_of = |lord: DashaLord| {
+        planets
+            .iter()
+            .find(|p| DashaLord::from_name(&p.name) == Some(lord))
+            .map(|p| normalize_deg(p.sidereal_deg))
+    };
+
+    let sun = lon_of(DashaLord::Sun)?;
+    let moon = lon_of(DashaLord::Moon)?;
+    let rahu = lon_of(DashaLord::Rahu)?;
+
+    let sign_of = |lon: f64| (lon / 30.0).floor() as u8 % 12;
+
+    // ---------- Rahu from lagna lord ----------
+    let lagna = sign_of(normalize_deg(asc_sid_deg));
+    let lagna_lord = lon_of(Sign::from_index(lagna).lord())?;
+
+    let rahu_sign = sign_of(rahu);
+    let h = (rahu_sign + 12 - sign_of(lagna_lord)) % 12 + 1;
+    let rahu_from_lagna_lord = matches!(h, 1 | 4 | 5 | 7 | 9 | 10) && rahu_sign != lagna;
+
+    // ---------- Paksha and day / night ----------
+    let shukla = normalize_deg(moon - sun) < 180.0;
+    let day = normalize_deg(sun - asc_sid_deg) >= 180.0;
+    let paksha_day_night = shukla != day;
+
+    Some(AshtottariApplicability {
+        rahu_from_lagna_lord,
+        paksha_day_night,
+    })
+}
+
+#[inline]
+fn normalize_deg(mut x: f64) -> f64 {
+    x %= 360.0;
+    if x < 0.0 {
+        x += 360.0;
+    }
+    x
+}
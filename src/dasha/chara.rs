@@ -0,0 +1,97 @@
+// ======================================================
+// Jaimini Chara Dasha
+// ======================================================
+//
+// Twelve sign dashas from the lagna. Each sign runs for the
+// count from the sign to its lord, less one (12 when the lord
+// is in the sign). A second cycle of 12 − n years follows.
+//
+// Variants:
+// - K.N. Rao   : direction from the footedness of the 9th house;
+//                counts by footedness of the dasha sign
+// - Sanjay Rath: direction from odd/even lagna; counts by
+//                odd/even dasha sign; ±1 year for an exalted /
+//                debilitated lord
+
+use crate::dasha::rashi::{
+    RashiAntarPeriod, RashiChart, RashiPeriod, equal_antardashas, is_odd, is_savya, rashi_periods,
+    regular_sequence, with_second_cycle, years_to_lord,
+};
+use crate::dasha::year::DashaClock;
+
+/// Published Chara dasha variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CharaVariant {
+    #[default]
+    KnRao,
+    SanjayRath,
+}
+
+impl CharaVariant {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CharaVariant::KnRao => "K.N. Rao",
+            CharaVariant::SanjayRath => "Sanjay Rath",
+        }
+    }
+
+    /// Dasha direction for the chart
+    pub fn forward(&self, lagna: u8) -> bool {
+        match self {
+            CharaVariant::KnRao => is_savya(lagna + 8),
+            CharaVariant::SanjayRath => is_odd(lagna),
+        }
+    }
+
+    /// Counting direction from a dasha sign to its lord
+    fn counts_forward(&self, sign: u8) -> bool {
+        match self {
+            CharaVariant::KnRao => is_savya(sign),
+            CharaVariant::SanjayRath => is_odd(sign),
+        }
+    }
+}
+
+/// First-cycle years of a sign (1..=12, 13 with an exalted lord)
+pub fn chara_dasha_years(chart: &RashiChart, sign: u8, variant: CharaVariant) -> f64 {
+    years_to_lord(
+        chart,
+        sign,
+        variant.counts_forward(sign),
+        variant == CharaVariant::SanjayRath,
+    )
+}
+
+/// Chara mahadashas from birth: two cycles of 12 signs
+///
+/// Signs whose second-cycle length is 0 are skipped.
+pub fn chara_dasha(
+    clock: &DashaClock,
+    chart: &RashiChart,
+    variant: CharaVariant,
+) -> Vec<RashiPeriod> {
+    let first = regular_sequence(chart.lagna, variant.forward(chart.lagna))
+        .into_iter()
+        .map(|sign| (sign, chara_dasha_years(chart, sign, variant)))
+        .collect();
+
+    rashi_periods(clock, with_second_cycle(first))
+}
+
+/// Chara antardashas: 12 equal parts of each mahadasha
+///
+/// K.N. Rao follows the main dasha direction; Sanjay Rath
+/// follows odd/even of the dasha sign.
+pub fn chara_antardashas(
+    clock: &DashaClock,
+    chart: &RashiChart,
+    variant: CharaVariant,
+) -> Vec<RashiAntarPeriod> {
+    let maha = chara_dasha(clock, chart, variant);
+    let forward = variant.forward(chart.lagna);
+
+    equal_antardashas(clock, &maha, |sign| match variant {
+        CharaVariant::KnRao => forward,
+        CharaVariant::SanjayRath => is_odd(sign),
+    })
+}
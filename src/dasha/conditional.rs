@@ -0,0 +1,268 @@
+// ======================================================
+// Conditional Nakshatra Dashas (BPHS ch. 46)
+// ======================================================
+//
+// Each system is a table for the nakshatra dasha engine plus
+// the chart condition under which BPHS prescribes it. Houses
+// are whole-sign from the lagna.
+
+use crate::PlanetJS;
+use crate::astrology::houses::Sign;
+use crate::dasha::nakshatra_dasha::{NakshatraDasha, NakshatraMapping};
+use crate::dasha::vimshottari::DashaLord;
+use crate::divisional::{VargaScheme, varga_position};
+
+use DashaLord::*;
+
+// ------------------ TABLES ------------------
+
+/// 116 years, counted from Pushya
+pub const SHODASHOTTARI: NakshatraDasha = NakshatraDasha {
+    name: "Shodashottari",
+    lords: &[
+        (Sun, 11.0),
+        (Mars, 12.0),
+        (Jupiter, 13.0),
+        (Saturn, 14.0),
+        (Ketu, 15.0),
+        (Moon, 16.0),
+        (Mercury, 17.0),
+        (Venus, 18.0),
+    ],
+    mapping: NakshatraMapping::Count {
+        from: 7,
+        reverse: false,
+    },
+    cycle_years: 116.0,
+};
+
+/// 112 years, counted from the janma nakshatra to Revati
+pub const DWADASHOTTARI: NakshatraDasha = NakshatraDasha {
+    name: "Dwadashottari",
+    lords: &[
+        (Sun, 7.0),
+        (Jupiter, 9.0),
+        (Ketu, 11.0),
+        (Mercury, 13.0),
+        (Rahu, 15.0),
+        (Mars, 17.0),
+        (Saturn, 19.0),
+        (Moon, 21.0),
+    ],
+    mapping: NakshatraMapping::Count {
+        from: 26,
+        reverse: true,
+    },
+    cycle_years: 112.0,
+};
+
+/// 105 years, counted from Anuradha
+pub const PANCHOTTARI: NakshatraDasha = NakshatraDasha {
+    name: "Panchottari",
+    lords: &[
+        (Sun, 12.0),
+        (Mercury, 13.0),
+        (Saturn, 14.0),
+        (Mars, 15.0),
+        (Venus, 16.0),
+        (Moon, 17.0),
+        (Jupiter, 18.0),
+    ],
+    mapping: NakshatraMapping::Count {
+        from: 16,
+        reverse: false,
+    },
+    cycle_years: 105.0,
+};
+
+/// 100 years, counted from Revati
+pub const SHATABDIKA: NakshatraDasha = NakshatraDasha {
+    name: "Shatabdika",
+    lords: &[
+        (Sun, 5.0),
+        (Moon, 5.0),
+        (Venus, 10.0),
+        (Mercury, 10.0),
+        (Jupiter, 20.0),
+        (Mars, 20.0),
+        (Saturn, 30.0),
+    ],
+    mapping: NakshatraMapping::Count {
+        from: 26,
+        reverse: false,
+    },
+    cycle_years: 100.0,
+};
+
+/// 84 years (7 × 12), counted from Swati
+pub const CHATURASHITI_SAMA: NakshatraDasha = NakshatraDasha {
+    name: "Chaturashiti-sama",
+    lords: &[
+        (Sun, 12.0),
+        (Moon, 12.0),
+        (Mars, 12.0),
+        (Mercury, 12.0),
+        (Jupiter, 12.0),
+        (Venus, 12.0),
+        (Saturn, 12.0),
+    ],
+    mapping: NakshatraMapping::Count {
+        from: 14,
+        reverse: false,
+    },
+    cycle_years: 84.0,
+};
+
+/// 72 years (8 × 9), counted from Mula
+pub const DWISAPTATI_SAMA: NakshatraDasha = NakshatraDasha {
+    name: "Dwisaptati-sama",
+    lords: &[
+        (Sun, 9.0),
+        (Moon, 9.0),
+        (Mars, 9.0),
+        (Mercury, 9.0),
+        (Jupiter, 9.0),
+        (Venus, 9.0),
+        (Saturn, 9.0),
+        (Rahu, 9.0),
+    ],
+    mapping: NakshatraMapping::Count {
+        from: 18,
+        reverse: false,
+    },
+    cycle_years: 72.0,
+};
+
+/// 60 years, nakshatra groups of 3 and 4 from Ashwini
+/// (Abhijit falls in Saturn's group of Shravana, Dhanishta)
+pub const SHASHTIHAYANI: NakshatraDasha = NakshatraDasha {
+    name: "Shashtihayani",
+    lords: &[
+        (Jupiter, 10.0),
+        (Sun, 10.0),
+        (Mars, 10.0),
+        (Moon, 6.0),
+        (Mercury, 6.0),
+        (Venus, 6.0),
+        (Saturn, 6.0),
+        (Rahu, 6.0),
+    ],
+    mapping: NakshatraMapping::Groups {
+        from: 0,
+        sizes: &[3, 4, 3, 4, 3, 4, 2, 4],
+    },
+    cycle_years: 60.0,
+};
+
+// ------------------ SYSTEMS ------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionalDasha {
+    Shodashottari,
+    Dwadashottari,
+    Panchottari,
+    Shatabdika,
+    ChaturashitiSama,
+    DwisaptatiSama,
+    Shashtihayani,
+}
+
+impl ConditionalDasha {
+    pub const ALL: [ConditionalDasha; 7] = [
+        ConditionalDasha::Shodashottari,
+        ConditionalDasha::Dwadashottari,
+        ConditionalDasha::Panchottari,
+        ConditionalDasha::Shatabdika,
+        ConditionalDasha::ChaturashitiSama,
+        ConditionalDasha::DwisaptatiSama,
+        ConditionalDasha::Shashtihayani,
+    ];
+
+    pub fn system(&self) -> &'static NakshatraDasha {
+        match self {
+            ConditionalDasha::Shodashottari => &SHODASHOTTARI,
+            ConditionalDasha::Dwadashottari => &DWADASHOTTARI,
+            ConditionalDasha::Panchottari => &PANCHOTTARI,
+            ConditionalDasha::Shatabdika => &SHATABDIKA,
+            ConditionalDasha::ChaturashitiSama => &CHATURASHITI_SAMA,
+            ConditionalDasha::DwisaptatiSama => &DWISAPTATI_SAMA,
+            ConditionalDasha::Shashtihayani => &SHASHTIHAYANI,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.system().name
+    }
+
+    /// The classical condition, in words
+    pub fn condition(&self) -> &'static str {
+        match self {
+            ConditionalDasha::Shodashottari => {
+                "Lagna in the Sun's hora in Krishna paksha, or the Moon's hora in Shukla paksha"
+            }
+            ConditionalDasha::Dwadashottari => "Navamsa lagna in a sign of Venus",
+            ConditionalDasha::Panchottari => "Lagna in Cancer with Cancer dwadashamsa",
+            ConditionalDasha::Shatabdika => "Vargottama lagna",
+            ConditionalDasha::ChaturashitiSama => "Lord of the 10th in the 10th",
+            ConditionalDasha::DwisaptatiSama => "Lagna lord in the 7th, or 7th lord in the lagna",
+            ConditionalDasha::Shashtihayani => "Sun in the lagna",
+        }
+    }
+
+    /// Whether the condition holds for a chart
+    ///
+    /// Input:
+    /// - asc_sid_deg : sidereal ascendant (degrees)
+    /// - planets     : sidereal positions of the grahas
+    ///
+    /// None if a needed planet is missing from `planets`.
+    pub fn applies(&self, asc_sid_deg: f64, planets: &[PlanetJS]) -> Option<bool> {
+        let sign_of_lon = |lon: f64| (normalize_deg(lon) / 30.0).floor() as u8 % 12;
+        let sign_of = |lord: DashaLord| {
+            planets
+                .iter()
+                .find(|p| DashaLord::from_name(&p.name) == Some(lord))
+                .map(|p| sign_of_lon(p.sidereal_deg))
+        };
+        let lon_of = |lord: DashaLord| {
+            planets
+                .iter()
+                .find(|p| DashaLord::from_name(&p.name) == Some(lord))
+                .map(|p| normalize_deg(p.sidereal_deg))
+        };
+
+        let lagna = sign_of_lon(asc_sid_deg);
+        let varga_lagna = |d: u8| varga_position(d, asc_sid_deg, VargaScheme::Parashari).0;
+        let lord_of_house = |h: u8| Sign::from_index(lagna + h - 1).lord();
+        let house_of = |sign: u8| (sign + 12 - lagna) % 12 + 1;
+
+        let holds = match self {
+            ConditionalDasha::Shodashottari => {
+                let shukla = normalize_deg(lon_of(Moon)? - lon_of(Sun)?) < 180.0;
+                // Parashari hora: Leo = Sun's hora, Cancer = Moon's hora
+                let sun_hora = varga_lagna(2) == 4;
+                sun_hora != shukla
+            }
+            ConditionalDasha::Dwadashottari => matches!(varga_lagna(9), 1 | 6),
+            ConditionalDasha::Panchottari => lagna == 3 && varga_lagna(12) == 3,
+            ConditionalDasha::Shatabdika => varga_lagna(9) == lagna,
+            ConditionalDasha::ChaturashitiSama => house_of(sign_of(lord_of_house(10))?) == 10,
+            ConditionalDasha::DwisaptatiSama => {
+                house_of(sign_of(lord_of_house(1))?) == 7
+                    || house_of(sign_of(lord_of_house(7))?) == 1
+            }
+            ConditionalDasha::Shashtihayani => sign_of(Sun)? == lagna,
+        };
+
+        Some(holds)
+    }
+}
+
+#[inline]
+fn normalize_deg(mut x: f64) -> f64 {
+    x %= 360.0;
+    if x < 0.0 {
+        x += 360.0;
+    }
+    x
+}
@@ -0,0 +1,60 @@
+// ======================================================
+// Drig Dasha (Jaimini)
+// ======================================================
+//
+// The 9th, 10th and 11th from the lagna each open a group of
+// four: the sign itself, then the three signs it aspects by
+// rashi drishti, counted zodiacally from an odd sign and in
+// reverse from an even one. Years follow the Narayana rule.
+//
+// With a dual lagna the fixed 9th and movable 11th aspect each
+// other; each then takes the adjacent sign that rashi drishti
+// skips instead of the other, so all 12 signs run once.
+
+use crate::dasha::rashi::{
+    RashiAntarPeriod, RashiChart, RashiPeriod, SignKind, count_signs, equal_antardashas, is_odd,
+    is_savya, rashi_aspects, rashi_periods, sign_kind, step_sign, years_to_lord,
+};
+use crate::dasha::year::DashaClock;
+
+/// Drig order of the 12 signs
+pub fn drig_sequence(lagna: u8) -> Vec<u8> {
+    let leaders = [9, 10, 11].map(|house| step_sign(lagna, house - 1, true));
+    let mut out = Vec::with_capacity(12);
+
+    for sign in leaders {
+        let forward = is_odd(sign);
+
+        let mut aspected: Vec<u8> = (0..12)
+            .filter(|&t| rashi_aspects(sign, t) && !leaders.contains(&t))
+            .collect();
+
+        if aspected.len() < 3 {
+            let skipped = match sign_kind(sign) {
+                SignKind::Movable => step_sign(sign, 1, true),
+                _ => step_sign(sign, 1, false),
+            };
+            aspected.push(skipped);
+        }
+        aspected.sort_by_key(|&t| count_signs(sign, t, forward));
+
+        out.push(sign);
+        out.extend(aspected);
+    }
+
+    out
+}
+
+pub fn drig_dasha(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiPeriod> {
+    let signs = drig_sequence(chart.lagna)
+        .into_iter()
+        .map(|sign| (sign, years_to_lord(chart, sign, is_savya(sign), true)));
+
+    rashi_periods(clock, signs)
+}
+
+/// 12 equal antardashas from the sign after the dasha sign,
+/// zodiacal for odd dasha signs
+pub fn drig_antardashas(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiAntarPeriod> {
+    equal_antardashas(clock, &drig_dasha(clock, chart), is_odd)
+}
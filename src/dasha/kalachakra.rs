@@ -0,0 +1,329 @@
+// ======================================================
+// Kalachakra Dasha
+// ======================================================
+//
+// Each nakshatra pada (navamsa) owns a run of 9 sign dashas;
+// the first sign is its deha (body) and the last its jeeva
+// (life). Sign years: Aries 7, Taurus 16, Gemini 9, Cancer 21,
+// Leo 5, Virgo 9, Libra 16, Scorpio 7, Sagittarius 10,
+// Capricorn 4, Aquarius 4, Pisces 10.
+//
+// Nakshatras alternate in threes between the savya (direct)
+// and apasavya (reverse) chakras, starting with Ashwini savya.
+// Savya padas follow the Ashwini / Bharani tables; apasavya
+// padas follow the same path mirrored (Rohini = Bharani
+// reversed, Mrigashira = Ashwini reversed).
+//
+// The birth balance is the unelapsed part of the birth pada's
+// paramayus (the total of its 9 signs). Dashas then continue
+// through the following padas.
+
+use crate::astrology::nakshatra::NAKSHATRA_NAMES;
+use crate::astrology::nakshatra_calc::compute_nakshatra;
+use crate::dasha::year::DashaClock;
+
+// ------------------ CONSTANTS ------------------
+
+// Dashas are generated until this many years after birth
+const SPAN_YEARS: f64 = 120.0;
+
+const AR: u8 = 0;
+const TA: u8 = 1;
+const GE: u8 = 2;
+const CN: u8 = 3;
+const LE: u8 = 4;
+const VI: u8 = 5;
+const LI: u8 = 6;
+const SC: u8 = 7;
+const SG: u8 = 8;
+const CP: u8 = 9;
+const AQ: u8 = 10;
+const PI: u8 = 11;
+
+/// Savya padas of Ashwini (also Krittika, Punarvasu, ...)
+const SAVYA_ASHWINI: [[u8; 9]; 4] = [
+    [AR, TA, GE, CN, LE, VI, LI, SC, SG],
+    [CP, AQ, PI, SC, LI, VI, CN, LE, GE],
+    [TA, AR, PI, AQ, CP, SG, AR, TA, GE],
+    [CN, LE, VI, LI, SC, SG, CP, AQ, PI],
+];
+
+/// Savya padas of Bharani (also Pushya, Chitra, ...)
+const SAVYA_BHARANI: [[u8; 9]; 4] = [
+    [SC, LI, VI, CN, LE, GE, TA, AR, PI],
+    [AQ, CP, SG, AR, TA, GE, CN, LE, VI],
+    [LI, SC, SG, CP, AQ, PI, SC, LI, VI],
+    [CN, LE, GE, TA, AR, PI, AQ, CP, SG],
+];
+
+// ------------------ TYPES ------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chakra {
+    Savya,
+    Apasavya,
+}
+
+impl Chakra {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Chakra::Savya => "Savya",
+            Chakra::Apasavya => "Apasavya",
+        }
+    }
+}
+
+/// How the dasha moved into a sign
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gati {
+    /// Next sign in the current direction
+    Normal,
+    /// Frog jump over one sign (Virgo → Cancer, Leo → Gemini)
+    Manduka,
+    /// Monkey step back against the run (Cancer ↔ Leo)
+    Markati,
+    /// Lion's glance to a trine (Pisces → Scorpio, Sagittarius → Aries)
+    Simhavalokana,
+}
+
+impl Gati {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Gati::Normal => "Normal",
+            Gati::Manduka => "Manduka",
+            Gati::Markati => "Markati",
+            Gati::Simhavalokana => "Simhavalokana",
+        }
+    }
+}
+
+/// One nakshatra pada of the Kalachakra
+#[derive(Debug, Clone, Copy)]
+pub struct KalachakraPada {
+    pub nakshatra: usize, // 0..26
+    pub pada: u8,         // 1..4
+    pub chakra: Chakra,
+    pub signs: [u8; 9],
+    pub deha: u8,
+    pub jeeva: u8,
+    /// Total years of the 9 signs
+    pub paramayus: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct KalachakraPeriod {
+    pub sign: u8,
+    pub years: f64,
+    pub start_jd: f64,
+    pub end_jd: f64,
+    /// Pada this dasha belongs to (deha / jeeva / chakra)
+    pub pada: KalachakraPada,
+    /// Position of the sign in `pada.signs` (0..8)
+    pub position: u8,
+    pub gati: Gati,
+}
+
+#[derive(Debug, Clone)]
+pub struct KalachakraAntarPeriod {
+    pub maha: u8,
+    pub antara: u8,
+    pub start_jd: f64,
+    pub end_jd: f64,
+}
+
+/// Birth pada of the Moon with its deha and jeeva
+#[derive(Debug, Clone, Copy)]
+pub struct KalachakraBirth {
+    pub nakshatra_name: &'static str,
+    pub pada: KalachakraPada,
+    /// Years of the birth pada already elapsed at birth
+    pub elapsed_years: f64,
+}
+
+// ------------------ TABLES ------------------
+
+/// Kalachakra years of a sign
+pub fn kalachakra_years(sign: u8) -> f64 {
+    match sign % 12 {
+        AR | SC => 7.0,
+        TA | LI => 16.0,
+        GE | VI => 9.0,
+        CN => 21.0,
+        LE => 5.0,
+        SG | PI => 10.0,
+        _ => 4.0, // Capricorn, Aquarius
+    }
+}
+
+pub fn chakra_of(nakshatra: usize) -> Chakra {
+    if (nakshatra % 27 / 3).is_multiple_of(2) {
+        Chakra::Savya
+    } else {
+        Chakra::Apasavya
+    }
+}
+
+/// Pada `pada` (1..4) of `nakshatra` (0..26)
+pub fn kalachakra_pada(nakshatra: usize, pada: u8) -> KalachakraPada {
+    let nakshatra = nakshatra % 27;
+    let p = (pada.clamp(1, 4) - 1) as usize;
+
+    let mirrored = |table: &[[u8; 9]; 4]| {
+        let mut s = table[3 - p];
+        s.reverse();
+        s
+    };
+
+    let signs = match nakshatra % 6 {
+        0 | 2 => SAVYA_ASHWINI[p],
+        1 => SAVYA_BHARANI[p],
+        4 => mirrored(&SAVYA_ASHWINI),
+        _ => mirrored(&SAVYA_BHARANI), // 3, 5
+    };
+
+    KalachakraPada {
+        nakshatra,
+        pada: p as u8 + 1,
+        chakra: chakra_of(nakshatra),
+        signs,
+        deha: signs[0],
+        jeeva: signs[8],
+        paramayus: signs.iter().map(|&s| kalachakra_years(s)).sum(),
+    }
+}
+
+/// Pada following `p` in zodiacal order
+fn next_pada(p: &KalachakraPada) -> KalachakraPada {
+    if p.pada == 4 {
+        kalachakra_pada(p.nakshatra + 1, 1)
+    } else {
+        kalachakra_pada(p.nakshatra, p.pada + 1)
+    }
+}
+
+/// Gati of the move `from` → `to`, given the previous move
+pub fn gati(from: u8, to: u8, prev: Option<(u8, u8)>) -> Gati {
+    let step = |a: u8, b: u8| (b % 12 + 12 - a % 12) % 12;
+    let backward = |d: u8| d >= 10;
+
+    let d = step(from, to);
+    match d {
+        4 | 8 => Gati::Simhavalokana,
+        2 | 10 => Gati::Manduka,
+        _ => match prev.map(|(a, b)| step(a, b)) {
+            Some(pd) if matches!(pd, 1 | 2 | 10 | 11) && backward(pd) != backward(d) => {
+                Gati::Markati
+            }
+            _ => Gati::Normal,
+        },
+    }
+}
+
+// ======================================================
+// BIRTH
+// ======================================================
+
+pub fn kalachakra_birth(moon_sid_deg: f64) -> KalachakraBirth {
+    let nak = compute_nakshatra(moon_sid_deg);
+
+    let quarter = (nak.fraction_elapsed * 4.0).min(3.999_999_999);
+    let pada = kalachakra_pada(nak.index, quarter.floor() as u8 + 1);
+
+    KalachakraBirth {
+        nakshatra_name: NAKSHATRA_NAMES[nak.index],
+        pada,
+        elapsed_years: quarter.fract() * pada.paramayus,
+    }
+}
+
+// ======================================================
+// 1️⃣ MAHĀDASHĀ
+// ======================================================
+
+/// Kalachakra mahadashas covering 120 years from birth
+///
+/// The first period is the sign running at birth.
+pub fn kalachakra_mahadasha_timeline(
+    clock: &DashaClock,
+    moon_sid_deg: f64,
+) -> Vec<KalachakraPeriod> {
+    let birth = kalachakra_birth(moon_sid_deg);
+    let end = clock.jd(SPAN_YEARS);
+
+    let mut pada = birth.pada;
+    let mut cur = clock.jd(-birth.elapsed_years);
+    let mut prev: Option<u8> = None;
+    let mut prev_move: Option<(u8, u8)> = None;
+
+    let mut out = Vec::new();
+
+    loop {
+        for (position, &sign) in pada.signs.iter().enumerate() {
+            let years = kalachakra_years(sign);
+            let end_jd = clock.advance(cur, years);
+
+            let g = match prev {
+                Some(from) => gati(from, sign, prev_move),
+                None => Gati::Normal,
+            };
+            prev_move = prev.map(|from| (from, sign));
+            prev = Some(sign);
+
+            // Signs wholly elapsed before birth are skipped
+            if end_jd > clock.birth_jd {
+                out.push(KalachakraPeriod {
+                    sign,
+                    years,
+                    start_jd: cur,
+                    end_jd,
+                    pada,
+                    position: position as u8,
+                    gati: g,
+                });
+            }
+            cur = end_jd;
+        }
+
+        if cur >= end {
+            break;
+        }
+        pada = next_pada(&pada);
+    }
+
+    out
+}
+
+// ======================================================
+// 2️⃣ ANTARDASHĀ
+// ======================================================
+
+/// Antardashas run through the 9 signs of the mahadasha's pada,
+/// starting from the mahadasha sign, each in proportion to its
+/// years over the paramayus.
+pub fn kalachakra_antardasha_timeline(
+    clock: &DashaClock,
+    moon_sid_deg: f64,
+) -> Vec<KalachakraAntarPeriod> {
+    let maha = kalachakra_mahadasha_timeline(clock, moon_sid_deg);
+    let mut out = Vec::new();
+
+    for m in maha {
+        let pos = m.position as usize;
+        let mut cur = m.start_jd;
+
+        for i in 0..9 {
+            let antara = m.pada.signs[(pos + i) % 9];
+            let end_jd = clock.advance(cur, m.years * kalachakra_years(antara) / m.pada.paramayus);
+
+            out.push(KalachakraAntarPeriod {
+                maha: m.sign,
+                antara,
+                start_jd: cur,
+                end_jd,
+            });
+            cur = end_jd;
+        }
+    }
+
+    out
+}
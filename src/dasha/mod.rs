@@ -1,6 +1,104 @@
+pub mod ashtottari;
+pub mod chara;
+pub mod conditional;
+pub mod drig;
+pub mod kalachakra;
+pub mod nakshatra_dasha;
+pub mod narayana;
+pub mod rashi;
+pub mod sandhi;
+pub mod seed;
+pub mod shoola;
+pub mod sthira;
 pub mod vimshottari;
-#[derive(Clone, Copy)]
-pub enum DashaMode {
-    Astronomical,    // pure Moon longitude
-    JHoraCompatible, // nakshatra-adjusted
+pub mod year;
+pub mod yogini;
+
+use chara::CharaVariant;
+use conditional::ConditionalDasha;
+use nakshatra_dasha::NakshatraDasha;
+use seed::DashaSeed;
+use year::DashaYear;
+
+/// Dasha system selectable for a kundli
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashaSystem {
+    #[default]
+    Vimshottari,
+    Yogini,
+    Ashtottari,
+    /// Shodashottari, Dwadashottari, ... (BPHS conditional dashas)
+    Conditional(ConditionalDasha),
+    Chara(CharaVariant),
+    /// Narayana dasha of a chart division (1 = rashi)
+    Narayana(u8),
+    Sthira,
+    Shoola,
+    NiryanaShoola,
+    Drig,
+    Kalachakra,
+}
+
+impl DashaSystem {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DashaSystem::Vimshottari => "Vimshottari",
+            DashaSystem::Yogini => "Yogini",
+            DashaSystem::Ashtottari => "Ashtottari",
+            DashaSystem::Conditional(c) => c.name(),
+            DashaSystem::Chara(CharaVariant::KnRao) => "CharaKNRao",
+            DashaSystem::Chara(CharaVariant::SanjayRath) => "CharaRath",
+            DashaSystem::Narayana(9) => "NarayanaD9",
+            DashaSystem::Narayana(10) => "NarayanaD10",
+            DashaSystem::Narayana(_) => "Narayana",
+            DashaSystem::Sthira => "Sthira",
+            DashaSystem::Shoola => "Shoola",
+            DashaSystem::NiryanaShoola => "NiryanaShoola",
+            DashaSystem::Drig => "Drig",
+            DashaSystem::Kalachakra => "Kalachakra",
+        }
+    }
+
+    /// Table for the nakshatra dasha engine, if this is a
+    /// planet-lord nakshatra dasha
+    pub fn nakshatra_system(&self) -> Option<&'static NakshatraDasha> {
+        match self {
+            DashaSystem::Vimshottari => Some(&vimshottari::VIMSHOTTARI),
+            DashaSystem::Ashtottari => Some(&ashtottari::ASHTOTTARI),
+            DashaSystem::Conditional(c) => Some(c.system()),
+            _ => None,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Vimshottari" => Some(DashaSystem::Vimshottari),
+            "Yogini" => Some(DashaSystem::Yogini),
+            "Ashtottari" => Some(DashaSystem::Ashtottari),
+            "Chara" | "CharaKNRao" => Some(DashaSystem::Chara(CharaVariant::KnRao)),
+            "CharaRath" => Some(DashaSystem::Chara(CharaVariant::SanjayRath)),
+            "Narayana" => Some(DashaSystem::Narayana(1)),
+            "NarayanaD9" => Some(DashaSystem::Narayana(9)),
+            "NarayanaD10" => Some(DashaSystem::Narayana(10)),
+            "Sthira" => Some(DashaSystem::Sthira),
+            "Shoola" => Some(DashaSystem::Shoola),
+            "NiryanaShoola" => Some(DashaSystem::NiryanaShoola),
+            "Drig" => Some(DashaSystem::Drig),
+            "Kalachakra" => Some(DashaSystem::Kalachakra),
+            _ => ConditionalDasha::ALL
+                .into_iter()
+                .find(|c| c.name() == name)
+                .map(DashaSystem::Conditional),
+        }
+    }
+}
+
+/// Dasha settings for a kundli
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DashaConfig {
+    pub system: DashaSystem,
+    /// Length of a dasha year
+    pub year: DashaYear,
+    /// Starting point of the nakshatra dashas
+    pub seed: DashaSeed,
 }
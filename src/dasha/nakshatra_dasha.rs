@@ -0,0 +1,509 @@
+// ======================================================
+// Nakshatra Dasha Engine — table-driven
+// ======================================================
+//
+// Vimshottari, Ashtottari and the conditional dashas of BPHS
+// differ only in their lords, years, nakshatra-to-lord mapping
+// and cycle length. A `NakshatraDasha` describes one system;
+// the timeline functions below work for any of them.
+//
+// The timelines start from the nakshatra of `seed_sid_deg`:
+// usually the sidereal Moon, or another seed (`DashaSeed`).
+
+use crate::{
+    astrology::nakshatra_calc::compute_nakshatra,
+    dasha::{
+        vimshottari::{AntarPeriod, DashaLord, DashaPeriod, PratyPeriod},
+        year::DashaClock,
+    },
+};
+
+/// How the janma nakshatra selects the first lord
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NakshatraMapping {
+    /// Count from `from` to the janma nakshatra (inclusive;
+    /// from the janma nakshatra to `from` when `reverse`); the
+    /// remainder by the number of lords picks the lord. The
+    /// birth balance is the unelapsed part of the nakshatra.
+    Count { from: usize, reverse: bool },
+    /// Consecutive runs of `sizes[i]` nakshatras from `from`
+    /// belong to lord `i`. The birth balance is the unelapsed
+    /// part of the whole run.
+    Groups {
+        from: usize,
+        sizes: &'static [usize],
+    },
+}
+
+/// A nakshatra-based dasha system
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NakshatraDasha {
+    pub name: &'static str,
+    /// Lords in dasha order with their years
+    pub lords: &'static [(DashaLord, f64)],
+    pub mapping: NakshatraMapping,
+    /// Full cycle in years (sum of the lords' years)
+    pub cycle_years: f64,
+}
+
+impl NakshatraDasha {
+    /// Years of a lord (None if it has no dasha in this system)
+    pub fn years(&self, lord: DashaLord) -> Option<f64> {
+        self.lords.iter().find(|l| l.0 == lord).map(|l| l.1)
+    }
+
+    /// Check that every lord of a period chain has a dasha in this
+    /// system, returning the first that does not
+    pub fn check_chain(&self, lords: &[DashaLord]) -> Result<(), DashaLord> {
+        match lords.iter().find(|&&l| self.years(l).is_none()) {
+            Some(&lord) => Err(lord),
+            None => Ok(()),
+        }
+    }
+
+    /// Next lord in dasha order (a lord outside the system maps to itself)
+    pub fn next(&self, lord: DashaLord) -> DashaLord {
+        match self.lords.iter().position(|l| l.0 == lord) {
+            Some(i) => self.lords[(i + 1) % self.lords.len()].0,
+            None => lord,
+        }
+    }
+
+    /// Lord of a nakshatra (0 = Ashwini) with the nakshatra's
+    /// (position in its run, run length); runs are 1 long for
+    /// counted mappings.
+    pub fn lord_of(&self, nakshatra_index: usize) -> (DashaLord, usize, usize) {
+        let n = self.lords.len();
+        let nak = nakshatra_index % 27;
+
+        match self.mapping {
+            NakshatraMapping::Count { from, reverse } => {
+                let count = if reverse {
+                    (from % 27 + 27 - nak) % 27
+                } else {
+                    (nak + 27 - from % 27) % 27
+                };
+                (self.lords[count % n].0, 0, 1)
+            }
+            NakshatraMapping::Groups { from, sizes } => {
+                let mut k = (nak + 27 - from % 27) % 27;
+                for (i, &size) in sizes.iter().enumerate() {
+                    if k < size {
+                        return (self.lords[i % n].0, k, size);
+                    }
+                    k -= size;
+                }
+                (self.lords[0].0, 0, 1)
+            }
+        }
+    }
+
+    fn years_or_zero(&self, lord: DashaLord) -> f64 {
+        self.years(lord).unwrap_or(0.0)
+    }
+}
+
+// ======================================================
+// 1️⃣ MAHĀDASHĀ
+// ======================================================
+
+/// One full cycle of mahadashas, the first running at birth
+pub fn nakshatra_mahadasha_timeline(
+    system: &NakshatraDasha,
+    clock: &DashaClock,
+    seed_sid_deg: f64,
+) -> Vec<DashaPeriod> {
+    let nak = compute_nakshatra(seed_sid_deg);
+
+    let (start_lord, pos, run) = system.lord_of(nak.index);
+    let frac_elapsed = (pos as f64 + nak.fraction_elapsed) / run as f64;
+
+    let elapsed_years = frac_elapsed * system.years_or_zero(start_lord);
+
+    let mut current_jd = clock.jd(-elapsed_years);
+    let mut lord = start_lord;
+
+    let mut out = Vec::new();
+
+    for _ in 0..system.lords.len() {
+        let end_jd = clock.advance(current_jd, system.years_or_zero(lord));
+
+        out.push(DashaPeriod {
+            lord,
+            start_jd: current_jd,
+            end_jd,
+        });
+
+        current_jd = end_jd;
+        lord = system.next(lord);
+    }
+
+    out
+}
+
+/// Mahadasha lord running at birth and its unelapsed years
+pub fn birth_balance(system: &NakshatraDasha, seed_sid_deg: f64) -> (DashaLord, f64) {
+    let nak = compute_nakshatra(seed_sid_deg);
+
+    let (lord, pos, run) = system.lord_of(nak.index);
+    let frac_elapsed = (pos as f64 + nak.fraction_elapsed) / run as f64;
+
+    (lord, (1.0 - frac_elapsed) * system.years_or_zero(lord))
+}
+
+// ======================================================
+// 2️⃣ ANTARDASHĀ
+// ======================================================
+
+pub fn nakshatra_antardasha_timeline(
+    system: &NakshatraDasha,
+    clock: &DashaClock,
+    seed_sid_deg: f64,
+) -> Vec<AntarPeriod> {
+    let maha = nakshatra_mahadasha_timeline(system, clock, seed_sid_deg);
+    let mut out = Vec::new();
+
+    for m in maha {
+        let mut cur = m.start_jd;
+        let mut antara = m.lord;
+
+        for _ in 0..system.lords.len() {
+            let years =
+                system.years_or_zero(m.lord) * (system.years_or_zero(antara) / system.cycle_years);
+            let end_jd = clock.advance(cur, years);
+
+            out.push(AntarPeriod {
+                maha: m.lord,
+                antara,
+                start_jd: cur,
+                end_jd,
+            });
+
+            cur = end_jd;
+            antara = system.next(antara);
+        }
+    }
+
+    out
+}
+
+// ======================================================
+// 3️⃣ PRATYANTARDASHĀ
+// ======================================================
+
+pub fn nakshatra_pratyantardasha_timeline(
+    system: &NakshatraDasha,
+    clock: &DashaClock,
+    seed_sid_deg: f64,
+) -> Vec<PratyPeriod> {
+    let antara = nakshatra_antardasha_timeline(system, clock, seed_sid_deg);
+    let mut out = Vec::new();
+
+    for a in antara {
+        let mut cur = a.start_jd;
+        let mut praty = a.antara;
+
+        for _ in 0..system.lords.len() {
+            let years = system.years_or_zero(a.maha)
+                * (system.years_or_zero(a.antara) / system.cycle_years)
+                * (system.years_or_zero(praty) / system.cycle_years);
+            let end_jd = clock.advance(cur, years);
+
+            out.push(PratyPeriod {
+                maha: a.maha,
+                antara: a.antara,
+                praty,
+                start_jd: cur,
+                end_jd,
+            });
+
+            cur = end_jd;
+            praty = system.next(praty);
+        }
+    }
+
+    out
+}
+
+// ======================================================
+// ON-DEMAND LEVELS (SOOKSHMA, PRĀṆA, ...)
+// ======================================================
+
+/// Names of the dasha levels, mahadasha first
+pub const LEVEL_NAMES: [&str; 6] = [
+    "Mahadasha",
+    "Antardasha",
+    "Pratyantardasha",
+    "Sookshma",
+    "Prana",
+    "Deha",
+];
+
+/// Deepest level that can be requested (6 = deha)
+pub const MAX_DEPTH: usize = LEVEL_NAMES.len();
+
+/// A period at any depth, identified by its chain of lords
+/// (mahadasha lord first)
+#[derive(Debug, Clone)]
+pub struct DashaNode {
+    pub lords: Vec<DashaLord>,
+    pub start_jd: f64,
+    pub end_jd: f64,
+}
+
+impl DashaNode {
+    /// 1 = mahadasha, 2 = antardasha, ...
+    pub fn level(&self) -> usize {
+        self.lords.len()
+    }
+
+    /// Lord of this period (the last in the chain)
+    pub fn lord(&self) -> DashaLord {
+        self.lords[self.lords.len() - 1]
+    }
+
+    pub fn contains(&self, jd: f64) -> bool {
+        jd >= self.start_jd && jd < self.end_jd
+    }
+
+    /// Dasha years left in this period at `jd`
+    pub fn remaining_years(&self, clock: &DashaClock, jd: f64) -> f64 {
+        (clock.years(self.end_jd) - clock.years(jd)).max(0.0)
+    }
+}
+
+impl From<&DashaPeriod> for DashaNode {
+    fn from(p: &DashaPeriod) -> Self {
+        DashaNode {
+            lords: vec![p.lord],
+            start_jd: p.start_jd,
+            end_jd: p.end_jd,
+        }
+    }
+}
+
+/// Sub-periods of one period, starting from its own lord
+///
+/// Each lord takes its share of the period in proportion to
+/// its years over the cycle, so any depth can be reached
+/// without building the levels above it. The last sub-period
+/// ends exactly with the period, as in `DashaStream`.
+pub fn sub_periods(
+    system: &NakshatraDasha,
+    clock: &DashaClock,
+    node: &DashaNode,
+) -> Vec<DashaNode> {
+    let span = clock.years(node.end_jd) - clock.years(node.start_jd);
+    let mut cur = node.start_jd;
+    let mut lord = node.lord();
+
+    let n = system.lords.len();
+    let mut out = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let end_jd = if i == n - 1 {
+            node.end_jd
+        } else {
+            clock.advance(cur, span * system.years_or_zero(lord) / system.cycle_years)
+        };
+
+        let mut lords = node.lords.clone();
+        lords.push(lord);
+
+        out.push(DashaNode {
+            lords,
+            start_jd: cur,
+            end_jd,
+        });
+
+        cur = end_jd;
+        lord = system.next(lord);
+    }
+
+    out
+}
+
+/// Chain of periods active at `jd`, mahadasha first, down to
+/// `depth` levels (5 = prana)
+///
+/// Any date works: before birth or past the first cycle, the
+/// mahadashas simply repeat.
+pub fn active_chain(
+    system: &NakshatraDasha,
+    clock: &DashaClock,
+    seed_sid_deg: f64,
+    jd: f64,
+    depth: usize,
+) -> Vec<DashaNode> {
+    let mut chain = DashaStream::at(system, *clock, seed_sid_deg, jd, depth)
+        .active()
+        .to_vec();
+    chain.truncate(depth);
+    chain
+}
+
+/// Periods of one level overlapping `from_jd .. to_jd`, in order
+pub fn periods_between(
+    system: &NakshatraDasha,
+    clock: &DashaClock,
+    seed_sid_deg: f64,
+    from_jd: f64,
+    to_jd: f64,
+    depth: usize,
+) -> Vec<DashaNode> {
+    DashaStream::at(system, *clock, seed_sid_deg, from_jd, depth)
+        .take_while(|n| n.start_jd < to_jd)
+        .collect()
+}
+
+// ======================================================
+// STREAM — unbounded periods at any level
+// ======================================================
+
+/// Endless sequence of periods at one level
+///
+/// Starts at the period running at a given date and yields
+/// every following period of that level, continuing into the
+/// next cycle after the last lord. Only the current chain of
+/// parents is kept, so long ranges cost no memory.
+#[derive(Debug, Clone)]
+pub struct DashaStream<'a> {
+    system: &'a NakshatraDasha,
+    clock: DashaClock,
+    /// Current period at every level, mahadasha first
+    chain: Vec<DashaNode>,
+}
+
+impl<'a> DashaStream<'a> {
+    /// Stream starting at the period running at birth
+    pub fn new(
+        system: &'a NakshatraDasha,
+        clock: DashaClock,
+        seed_sid_deg: f64,
+        depth: usize,
+    ) -> Self {
+        Self::at(system, clock, seed_sid_deg, clock.birth_jd, depth)
+    }
+
+    /// Stream starting at the period running at `jd`
+    ///
+    /// `depth` is the level yielded (1 = mahadasha); it is
+    /// raised to 1 if zero.
+    pub fn at(
+        system: &'a NakshatraDasha,
+        clock: DashaClock,
+        seed_sid_deg: f64,
+        jd: f64,
+        depth: usize,
+    ) -> Self {
+        let nak = compute_nakshatra(seed_sid_deg);
+
+        let (first_lord, pos, run) = system.lord_of(nak.index);
+        let frac_elapsed = (pos as f64 + nak.fraction_elapsed) / run as f64;
+        let elapsed_years = frac_elapsed * system.years_or_zero(first_lord);
+
+        // Start of the cycle holding `jd`: whole cycles from the
+        // first mahadasha (which starts before birth)
+        let cycles = ((clock.years(jd) + elapsed_years) / system.cycle_years).floor();
+        let start_jd = clock.jd(cycles * system.cycle_years - elapsed_years);
+
+        let mut stream = DashaStream {
+            system,
+            clock,
+            chain: vec![DashaNode {
+                lords: vec![first_lord],
+                start_jd,
+                end_jd: clock.advance(start_jd, system.years_or_zero(first_lord)),
+            }],
+        };
+
+        // Rounding at a cycle boundary: stop at the last lord
+        for _ in 1..system.lords.len() {
+            if jd < stream.chain[0].end_jd {
+                break;
+            }
+            stream.step(0);
+        }
+
+        while stream.chain.len() < depth.max(1) {
+            let parent = &stream.chain[stream.chain.len() - 1];
+            let mut child = stream.child(parent, parent.lord(), parent.start_jd);
+
+            // Rounding at the very end of the parent: last sub-period
+            for _ in 1..system.lords.len() {
+                if jd < child.end_jd {
+                    break;
+                }
+                child = stream.child(parent, system.next(child.lord()), child.end_jd);
+            }
+            stream.chain.push(child);
+        }
+
+        stream
+    }
+
+    /// Current period at every level, mahadasha first
+    pub fn active(&self) -> &[DashaNode] {
+        &self.chain
+    }
+
+    /// Sub-period of `parent` for `lord`, starting at `start_jd`
+    ///
+    /// The last sub-period ends exactly with its parent, so the
+    /// stream has no rounding gaps.
+    fn child(&self, parent: &DashaNode, lord: DashaLord, start_jd: f64) -> DashaNode {
+        let end_jd = if self.system.next(lord) == parent.lord() {
+            parent.end_jd
+        } else {
+            let span = self.clock.years(parent.end_jd) - self.clock.years(parent.start_jd);
+            let years = span * self.system.years_or_zero(lord) / self.system.cycle_years;
+            self.clock.advance(start_jd, years)
+        };
+
+        let mut lords = parent.lords.clone();
+        lords.push(lord);
+
+        DashaNode {
+            lords,
+            start_jd,
+            end_jd,
+        }
+    }
+
+    /// Move the period at `level` (0 = mahadasha) to the next
+    /// one, carrying into the parent after its last sub-period
+    fn step(&mut self, level: usize) {
+        let cur = &self.chain[level];
+        let lord = self.system.next(cur.lord());
+
+        let next = if level == 0 {
+            DashaNode {
+                lords: vec![lord],
+                start_jd: cur.end_jd,
+                end_jd: self
+                    .clock
+                    .advance(cur.end_jd, self.system.years_or_zero(lord)),
+            }
+        } else if lord == self.chain[level - 1].lord() {
+            // Sub-periods start from the parent's own lord, so
+            // reaching it again closes the parent
+            self.step(level - 1);
+            let parent = &self.chain[level - 1];
+            self.child(parent, parent.lord(), parent.start_jd)
+        } else {
+            self.child(&self.chain[level - 1], lord, cur.end_jd)
+        };
+
+        self.chain[level] = next;
+    }
+}
+
+impl Iterator for DashaStream<'_> {
+    type Item = DashaNode;
+
+    fn next(&mut self) -> Option<DashaNode> {
+        let out = self.chain[self.chain.len() - 1].clone();
+        self.step(self.chain.len() - 1);
+        Some(out)
+    }
+}
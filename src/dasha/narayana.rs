@@ -0,0 +1,79 @@
+// ======================================================
+// Narayana Dasha (Jaimini)
+// ======================================================
+//
+// Starts from the stronger of the lagna and the 7th. The
+// progression depends on the starting sign:
+// - movable : consecutive signs
+// - fixed   : every 6th sign
+// - dual    : kendras (1, 4, 7, 10 from it, then the next sign)
+// Odd signs run zodiacally and even signs in reverse; Saturn in
+// the sign forces zodiacal order and Ketu reverses it.
+//
+// Years count from the sign to its lord (by footedness), less
+// one, ±1 for an exalted / debilitated lord, with a second
+// cycle of 12 − n years.
+//
+// Varga Narayana (D9, D10, ...) applies the same rules to the
+// varga chart; see `RashiChart::varga`.
+
+use crate::dasha::rashi::{
+    RashiAntarPeriod, RashiChart, RashiPeriod, SignKind, is_odd, is_savya, rashi_periods,
+    sequenced_antardashas, sign_kind, step_sign, stronger_lord, stronger_sign, with_second_cycle,
+    years_to_lord,
+};
+use crate::dasha::vimshottari::DashaLord;
+use crate::dasha::year::DashaClock;
+
+/// Narayana order of the 12 signs from `start`
+pub fn narayana_sequence(chart: &RashiChart, start: u8) -> [u8; 12] {
+    let occupied_by = |p: DashaLord| chart.sign_of(p) == Some(start % 12);
+
+    let mut forward = is_odd(start);
+    if occupied_by(DashaLord::Saturn) {
+        forward = true;
+    }
+    if occupied_by(DashaLord::Ketu) {
+        forward = !forward;
+    }
+
+    std::array::from_fn(|i| {
+        let i = i as u8;
+        let offset = match sign_kind(start) {
+            SignKind::Movable => i,
+            SignKind::Fixed => 5 * i,
+            SignKind::Dual => (i % 4) * 3 + i / 4,
+        };
+        step_sign(start, offset, forward)
+    })
+}
+
+/// Starting sign: stronger of the lagna and the 7th
+pub fn narayana_start(chart: &RashiChart) -> u8 {
+    stronger_sign(chart, chart.lagna, chart.lagna + 6)
+}
+
+/// Narayana mahadashas from birth (two cycles)
+pub fn narayana_dasha(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiPeriod> {
+    let first = narayana_sequence(chart, narayana_start(chart))
+        .into_iter()
+        .map(|sign| (sign, years_to_lord(chart, sign, is_savya(sign), true)))
+        .collect();
+
+    rashi_periods(clock, with_second_cycle(first))
+}
+
+/// Narayana antardashas
+///
+/// Each dasha is split into 12 equal parts in Narayana order
+/// from the paka rashi (the sign holding the dasha sign's lord).
+pub fn narayana_antardashas(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiAntarPeriod> {
+    let maha = narayana_dasha(clock, chart);
+
+    sequenced_antardashas(clock, &maha, |sign| {
+        let paka = stronger_lord(chart, sign)
+            .and_then(|l| chart.sign_of(l))
+            .unwrap_or(sign);
+        narayana_sequence(chart, paka)
+    })
+}
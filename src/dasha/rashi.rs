@@ -0,0 +1,433 @@
+// ======================================================
+// Rashi (sign-based) dashas — shared pieces
+// ======================================================
+//
+// Jaimini dashas run over signs rather than nakshatra lords.
+// Signs are indices 0..11 (0 = Aries). Longitudes are
+// SIDEREAL DEGREES.
+
+use std::cmp::Ordering;
+
+use crate::PlanetJS;
+use crate::astrology::dignity::exaltation_sign;
+use crate::astrology::houses::Sign;
+use crate::dasha::vimshottari::DashaLord;
+use crate::dasha::year::DashaClock;
+use crate::divisional::{VargaScheme, varga_position};
+
+// ------------------ CHART ------------------
+
+/// A planet's sign placement
+#[derive(Debug, Clone, Copy)]
+pub struct RashiPlacement {
+    pub planet: DashaLord,
+    pub sign: u8,
+    /// Degrees within the sign (0..30)
+    pub degree: f64,
+}
+
+/// Sign positions used by the rashi dashas
+#[derive(Debug, Clone)]
+pub struct RashiChart {
+    pub lagna: u8,
+    pub placements: Vec<RashiPlacement>,
+}
+
+impl RashiChart {
+    /// Rashi (D1) chart from sidereal positions
+    ///
+    /// Entries of `planets` that are not one of the nine grahas
+    /// are ignored.
+    pub fn new(asc_sid_deg: f64, planets: &[PlanetJS]) -> Self {
+        let placements = planets
+            .iter()
+            .filter_map(|p| {
+                let lon = normalize_deg(p.sidereal_deg);
+                Some(RashiPlacement {
+                    planet: DashaLord::from_name(&p.name)?,
+                    sign: (lon / 30.0).floor() as u8 % 12,
+                    degree: lon % 30.0,
+                })
+            })
+            .collect();
+
+        RashiChart {
+            lagna: (normalize_deg(asc_sid_deg) / 30.0).floor() as u8 % 12,
+            placements,
+        }
+    }
+
+    /// Varga chart (e.g. D9, D10) for the varga-based dashas
+    pub fn varga(
+        division: u8,
+        asc_sid_deg: f64,
+        planets: &[PlanetJS],
+        scheme: VargaScheme,
+    ) -> Self {
+        let placements = planets
+            .iter()
+            .filter_map(|p| {
+                let (sign, degree) = varga_position(division, p.sidereal_deg, scheme);
+                Some(RashiPlacement {
+                    planet: DashaLord::from_name(&p.name)?,
+                    sign,
+                    degree,
+                })
+            })
+            .collect();
+
+        RashiChart {
+            lagna: varga_position(division, asc_sid_deg, scheme).0,
+            placements,
+        }
+    }
+
+    pub fn placement(&self, planet: DashaLord) -> Option<&RashiPlacement> {
+        self.placements.iter().find(|p| p.planet == planet)
+    }
+
+    pub fn sign_of(&self, planet: DashaLord) -> Option<u8> {
+        self.placement(planet).map(|p| p.sign)
+    }
+
+    /// Number of grahas in `sign`
+    pub fn occupants(&self, sign: u8) -> usize {
+        self.placements
+            .iter()
+            .filter(|p| p.sign == sign % 12)
+            .count()
+    }
+}
+
+// ------------------ PERIOD STRUCTS ------------------
+
+#[derive(Debug, Clone)]
+pub struct RashiPeriod {
+    pub sign: u8,
+    pub years: f64,
+    pub start_jd: f64,
+    pub end_jd: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RashiAntarPeriod {
+    pub maha: u8,
+    pub antara: u8,
+    pub start_jd: f64,
+    pub end_jd: f64,
+}
+
+// ------------------ SIGN HELPERS ------------------
+
+/// Movable (chara), fixed (sthira) or dual (dwiswabhava)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignKind {
+    Movable,
+    Fixed,
+    Dual,
+}
+
+pub fn sign_kind(sign: u8) -> SignKind {
+    match sign % 3 {
+        0 => SignKind::Movable,
+        1 => SignKind::Fixed,
+        _ => SignKind::Dual,
+    }
+}
+
+/// Rashi drishti: movable signs aspect the fixed signs and fixed
+/// signs the movable ones, except the adjacent sign; dual signs
+/// aspect each other.
+pub fn rashi_aspects(from: u8, to: u8) -> bool {
+    let (from, to) = (from % 12, to % 12);
+    if from == to {
+        return false;
+    }
+
+    match (sign_kind(from), sign_kind(to)) {
+        (SignKind::Movable, SignKind::Fixed) => to != (from + 1) % 12,
+        (SignKind::Fixed, SignKind::Movable) => to != (from + 11) % 12,
+        (SignKind::Dual, SignKind::Dual) => true,
+        _ => false,
+    }
+}
+
+/// Savya (odd-footed) signs: Aries, Taurus, Gemini, Libra,
+/// Scorpio, Sagittarius. Dashas and counts from these run
+/// zodiacally; from the other six they run in reverse.
+pub fn is_savya(sign: u8) -> bool {
+    matches!(sign % 12, 0 | 1 | 2 | 6 | 7 | 8)
+}
+
+/// Odd sign (Aries, Gemini, ...)
+pub fn is_odd(sign: u8) -> bool {
+    (sign % 12).is_multiple_of(2)
+}
+
+/// Sign lord and, for Scorpio and Aquarius, the node co-lord
+pub fn sign_lords(sign: u8) -> (DashaLord, Option<DashaLord>) {
+    let lord = Sign::from_index(sign).lord();
+    let co_lord = match sign % 12 {
+        7 => Some(DashaLord::Ketu),
+        10 => Some(DashaLord::Rahu),
+        _ => None,
+    };
+    (lord, co_lord)
+}
+
+/// Inclusive count from `from` to `to` (1..=12)
+pub fn count_signs(from: u8, to: u8, forward: bool) -> u8 {
+    let d = if forward {
+        (to % 12 + 12 - from % 12) % 12
+    } else {
+        (from % 12 + 12 - to % 12) % 12
+    };
+    d + 1
+}
+
+/// `n` signs on from `sign` (0 = the sign itself)
+pub fn step_sign(sign: u8, n: u8, forward: bool) -> u8 {
+    if forward {
+        (sign % 12 + n % 12) % 12
+    } else {
+        (sign % 12 + 12 - n % 12) % 12
+    }
+}
+
+/// Lord used for a sign with two lords (Scorpio, Aquarius)
+///
+/// - a lord placed outside the sign is preferred to one in it
+/// - otherwise the lord with more planets in its sign
+/// - then an exalted lord
+/// - then the lord further advanced in its sign
+///
+/// Returns None when both lords occupy the sign itself.
+pub fn stronger_lord(chart: &RashiChart, sign: u8) -> Option<DashaLord> {
+    let (lord, co_lord) = sign_lords(sign);
+    let Some(co_lord) = co_lord else {
+        return Some(lord);
+    };
+
+    let (Some(a), Some(b)) = (chart.placement(lord), chart.placement(co_lord)) else {
+        return Some(lord);
+    };
+
+    let sign = sign % 12;
+    match (a.sign == sign, b.sign == sign) {
+        (true, true) => return None,
+        (true, false) => return Some(co_lord),
+        (false, true) => return Some(lord),
+        _ => {}
+    }
+
+    let exalted = |p: &RashiPlacement| exaltation_sign(p.planet) == Some(p.sign);
+
+    let by_occupants = chart.occupants(a.sign).cmp(&chart.occupants(b.sign));
+    let by_exaltation = exalted(a).cmp(&exalted(b));
+    let by_degree = a.degree.total_cmp(&b.degree);
+
+    if by_occupants.then(by_exaltation).then(by_degree).is_lt() {
+        Some(co_lord)
+    } else {
+        Some(lord)
+    }
+}
+
+/// Stronger of two signs (Jaimini first source of strength)
+///
+/// In order, the stronger sign:
+/// 1. holds more planets
+/// 2. is aspected (rashi drishti) or occupied by more of
+///    Jupiter, Mercury and its own lord
+/// 3. holds more exalted planets
+/// 4. is dual over fixed over movable
+/// 5. has its lord further advanced in its sign
+///
+/// Ties fall to `a`.
+pub fn stronger_sign(chart: &RashiChart, a: u8, b: u8) -> u8 {
+    let (a, b) = (a % 12, b % 12);
+
+    let aspect_strength = |sign: u8| {
+        let lord = stronger_lord(chart, sign).unwrap_or(Sign::from_index(sign).lord());
+        [DashaLord::Jupiter, DashaLord::Mercury, lord]
+            .iter()
+            .filter_map(|&p| chart.sign_of(p))
+            .filter(|&ps| ps == sign || rashi_aspects(ps, sign))
+            .count()
+    };
+
+    let exalted = |sign: u8| {
+        chart
+            .placements
+            .iter()
+            .filter(|p| p.sign == sign && exaltation_sign(p.planet) == Some(sign))
+            .count()
+    };
+
+    let kind_rank = |sign: u8| match sign_kind(sign) {
+        SignKind::Movable => 0,
+        SignKind::Fixed => 1,
+        SignKind::Dual => 2,
+    };
+
+    let lord_degree = |sign: u8| {
+        stronger_lord(chart, sign)
+            .and_then(|l| chart.placement(l))
+            .map(|p| p.degree)
+            .unwrap_or(0.0)
+    };
+
+    let order = chart
+        .occupants(a)
+        .cmp(&chart.occupants(b))
+        .then(aspect_strength(a).cmp(&aspect_strength(b)))
+        .then(exalted(a).cmp(&exalted(b)))
+        .then(kind_rank(a).cmp(&kind_rank(b)))
+        .then(lord_degree(a).total_cmp(&lord_degree(b)));
+
+    if order == Ordering::Less { b } else { a }
+}
+
+/// Twelve consecutive signs from `start`
+pub fn regular_sequence(start: u8, forward: bool) -> [u8; 12] {
+    std::array::from_fn(|i| step_sign(start, i as u8, forward))
+}
+
+/// Years from the count of a sign to its lord, less one
+///
+/// 12 when the lord is in the sign (or both lords are, for
+/// Scorpio and Aquarius). With `dignity_adjust` an exalted lord
+/// adds a year and a debilitated lord takes one away.
+pub fn years_to_lord(chart: &RashiChart, sign: u8, forward: bool, dignity_adjust: bool) -> f64 {
+    let Some(lord) = stronger_lord(chart, sign) else {
+        return 12.0;
+    };
+    let Some(p) = chart.placement(lord) else {
+        return 12.0;
+    };
+
+    let count = count_signs(sign, p.sign, forward);
+    let mut years = if count == 1 { 12 } else { count - 1 } as f64;
+
+    if dignity_adjust && let Some(exalt) = exaltation_sign(lord) {
+        if p.sign == exalt {
+            years += 1.0;
+        } else if p.sign == (exalt + 6) % 12 {
+            years -= 1.0;
+        }
+    }
+
+    years
+}
+
+/// Fixed Sthira lengths: movable 7, fixed 8, dual 9 years
+pub fn sthira_years(sign: u8) -> f64 {
+    match sign_kind(sign) {
+        SignKind::Movable => 7.0,
+        SignKind::Fixed => 8.0,
+        SignKind::Dual => 9.0,
+    }
+}
+
+/// Lay (sign, years) end to end from birth
+///
+/// Entries with no years are skipped.
+pub fn rashi_periods(
+    clock: &DashaClock,
+    signs: impl IntoIterator<Item = (u8, f64)>,
+) -> Vec<RashiPeriod> {
+    let mut cur = clock.birth_jd;
+    let mut out = Vec::new();
+
+    for (sign, years) in signs {
+        if years <= 0.0 {
+            continue;
+        }
+
+        let end_jd = clock.advance(cur, years);
+        out.push(RashiPeriod {
+            sign,
+            years,
+            start_jd: cur,
+            end_jd,
+        });
+        cur = end_jd;
+    }
+
+    out
+}
+
+/// First cycle as given, then a second cycle of 12 − n years
+pub fn with_second_cycle(first: Vec<(u8, f64)>) -> Vec<(u8, f64)> {
+    let second: Vec<(u8, f64)> = first.iter().map(|&(s, y)| (s, 12.0 - y)).collect();
+    first.into_iter().chain(second).collect()
+}
+
+// ------------------ ANTARDASHĀ ------------------
+
+/// Split each period into 12 equal sign antardashas
+///
+/// The sequence starts from the sign after the dasha sign and
+/// ends with the dasha sign itself; `forward` gives the
+/// direction for each dasha sign.
+pub fn equal_antardashas(
+    clock: &DashaClock,
+    maha: &[RashiPeriod],
+    forward: impl Fn(u8) -> bool,
+) -> Vec<RashiAntarPeriod> {
+    let mut out = Vec::new();
+
+    for m in maha {
+        let dir = forward(m.sign);
+        let mut cur = m.start_jd;
+
+        for i in 0..12 {
+            let end_jd = clock.advance(cur, m.years / 12.0);
+            out.push(RashiAntarPeriod {
+                maha: m.sign,
+                antara: step_sign(m.sign, i + 1, dir),
+                start_jd: cur,
+                end_jd,
+            });
+            cur = end_jd;
+        }
+    }
+
+    out
+}
+
+/// Split each period into 12 equal antardashas in the order
+/// given by `sequence` for that dasha sign
+pub fn sequenced_antardashas(
+    clock: &DashaClock,
+    maha: &[RashiPeriod],
+    sequence: impl Fn(u8) -> [u8; 12],
+) -> Vec<RashiAntarPeriod> {
+    let mut out = Vec::new();
+
+    for m in maha {
+        let mut cur = m.start_jd;
+
+        for antara in sequence(m.sign) {
+            let end_jd = clock.advance(cur, m.years / 12.0);
+            out.push(RashiAntarPeriod {
+                maha: m.sign,
+                antara,
+                start_jd: cur,
+                end_jd,
+            });
+            cur = end_jd;
+        }
+    }
+
+    out
+}
+
+#[inline]
+fn normalize_deg(mut x: f64) -> f64 {
+    x %= 360.0;
+    if x < 0.0 {
+        x += 360.0;
+    }
+    x
+}
@@ -0,0 +1,166 @@
+// ======================================================
+// Dasha Sandhi & Transitions
+// ======================================================
+//
+// A transition is the moment one period hands over to the next.
+// Its level is the highest level that changes: a mahadasha
+// boundary is also an antardasha boundary, but counts as a
+// mahadasha transition.
+//
+// Sandhi is the junction around a transition: the last part of
+// the outgoing period and the first part of the incoming one,
+// each a fraction of its own length at the transition's level.
+
+use crate::dasha::{
+    nakshatra_dasha::{DashaNode, DashaStream, NakshatraDasha},
+    rashi::RashiChart,
+    vimshottari::DashaLord,
+    year::DashaClock,
+};
+
+/// A lord's natal placement
+#[derive(Debug, Clone, Copy)]
+pub struct LordPlacement {
+    pub lord: DashaLord,
+    pub sign: u8,
+    /// Whole-sign house counted from the lagna (1..12)
+    pub house: u8,
+}
+
+impl LordPlacement {
+    pub fn of(chart: &RashiChart, lord: DashaLord) -> Option<Self> {
+        let sign = chart.sign_of(lord)?;
+
+        Some(LordPlacement {
+            lord,
+            sign,
+            house: (sign + 12 - chart.lagna) % 12 + 1,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DashaTransition {
+    pub jd: f64,
+    /// Highest level that changes (1 = mahadasha)
+    pub level: usize,
+    /// Chain ending at `jd`, mahadasha first
+    pub outgoing: Vec<DashaNode>,
+    /// Chain starting at `jd`, mahadasha first
+    pub incoming: Vec<DashaNode>,
+    /// Natal placements of the outgoing lords, mahadasha first
+    pub outgoing_placements: Vec<LordPlacement>,
+    /// Natal placements of the incoming lords, mahadasha first
+    pub incoming_placements: Vec<LordPlacement>,
+}
+
+impl DashaTransition {
+    /// Period ending at the transition, at its level
+    pub fn outgoing_period(&self) -> &DashaNode {
+        &self.outgoing[self.level - 1]
+    }
+
+    /// Period starting at the transition, at its level
+    pub fn incoming_period(&self) -> &DashaNode {
+        &self.incoming[self.level - 1]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SandhiWindow {
+    pub start_jd: f64,
+    pub end_jd: f64,
+    pub transition: DashaTransition,
+}
+
+impl SandhiWindow {
+    pub fn contains(&self, jd: f64) -> bool {
+        jd >= self.start_jd && jd < self.end_jd
+    }
+}
+
+/// Transitions between `from_jd` and `to_jd`, in order
+///
+/// Every boundary of the periods at `depth` (1 = mahadasha)
+/// gives one event.
+pub fn dasha_transitions(
+    system: &NakshatraDasha,
+    clock: &DashaClock,
+    seed_sid_deg: f64,
+    chart: &RashiChart,
+    from_jd: f64,
+    to_jd: f64,
+    depth: usize,
+) -> Vec<DashaTransition> {
+    let mut stream = DashaStream::at(system, *clock, seed_sid_deg, from_jd, depth);
+    let placements = |chain: &[DashaNode]| -> Vec<LordPlacement> {
+        chain
+            .iter()
+            .filter_map(|n| LordPlacement::of(chart, n.lord()))
+            .collect()
+    };
+
+    let mut out = Vec::new();
+
+    loop {
+        let outgoing = stream.active().to_vec();
+        stream.next();
+        let incoming = stream.active().to_vec();
+
+        let jd = incoming[incoming.len() - 1].start_jd;
+        if jd >= to_jd {
+            break;
+        }
+
+        let level = outgoing
+            .iter()
+            .zip(&incoming)
+            .position(|(a, b)| a.lord() != b.lord())
+            .unwrap_or(incoming.len() - 1)
+            + 1;
+
+        out.push(DashaTransition {
+            jd,
+            level,
+            outgoing_placements: placements(&outgoing),
+            incoming_placements: placements(&incoming),
+            outgoing,
+            incoming,
+        });
+    }
+
+    out
+}
+
+/// Sandhi windows of the transitions between `from_jd` and
+/// `to_jd`
+///
+/// `fraction` of the outgoing and of the incoming period (at
+/// the transition's level) on either side of the boundary;
+/// 0.1 is a common choice. Above 0.5 the windows of neighbouring
+/// transitions overlap.
+#[allow(clippy::too_many_arguments)]
+pub fn sandhi_windows(
+    system: &NakshatraDasha,
+    clock: &DashaClock,
+    seed_sid_deg: f64,
+    chart: &RashiChart,
+    from_jd: f64,
+    to_jd: f64,
+    depth: usize,
+    fraction: f64,
+) -> Vec<SandhiWindow> {
+    dasha_transitions(system, clock, seed_sid_deg, chart, from_jd, to_jd, depth)
+        .into_iter()
+        .map(|t| {
+            let out = t.outgoing_period();
+            let inc = t.incoming_period();
+
+            SandhiWindow {
+                start_jd: t.jd - fraction * (out.end_jd - out.start_jd),
+                end_jd: t.jd + fraction * (inc.end_jd - inc.start_jd),
+                transition: t,
+            }
+        })
+        .collect()
+}
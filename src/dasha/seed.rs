@@ -0,0 +1,90 @@
+// ======================================================
+// Dasha Seed — where a nakshatra dasha starts
+// ======================================================
+//
+// Nakshatra dashas normally start from the Moon's nakshatra.
+// Alternatives start from the lagna's nakshatra, from the
+// stronger of Moon and lagna, or from a star counted from the
+// Moon's (Kshema 4th, Utpanna 5th, Adhana 8th). A counted star
+// keeps the Moon's elapsed fraction, so the birth balance is
+// proportioned the same way.
+
+use crate::dasha::rashi::{RashiChart, stronger_sign};
+
+// Nakshatra length (degrees)
+const NAK_LEN: f64 = 360.0 / 27.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashaSeed {
+    #[default]
+    Moon,
+    Lagna,
+    /// Moon or lagna, whichever sign is stronger (Jaimini)
+    Stronger,
+    /// 4th star from the Moon
+    Kshema,
+    /// 5th star from the Moon
+    Utpanna,
+    /// 8th star from the Moon
+    Adhana,
+}
+
+impl DashaSeed {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DashaSeed::Moon => "Moon",
+            DashaSeed::Lagna => "Lagna",
+            DashaSeed::Stronger => "Stronger",
+            DashaSeed::Kshema => "Kshema",
+            DashaSeed::Utpanna => "Utpanna",
+            DashaSeed::Adhana => "Adhana",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Moon" => Some(DashaSeed::Moon),
+            "Lagna" => Some(DashaSeed::Lagna),
+            "Stronger" => Some(DashaSeed::Stronger),
+            "Kshema" => Some(DashaSeed::Kshema),
+            "Utpanna" => Some(DashaSeed::Utpanna),
+            "Adhana" => Some(DashaSeed::Adhana),
+            _ => None,
+        }
+    }
+
+    /// Seed longitude (sidereal degrees) for the dasha timelines
+    ///
+    /// Input:
+    /// - moon_sid_deg : sidereal Moon
+    /// - asc_sid_deg  : sidereal ascendant
+    /// - chart        : rashi chart, for `Stronger`
+    pub fn longitude(&self, moon_sid_deg: f64, asc_sid_deg: f64, chart: &RashiChart) -> f64 {
+        let from_moon = |star: f64| normalize_deg(moon_sid_deg + (star - 1.0) * NAK_LEN);
+
+        match self {
+            DashaSeed::Moon => normalize_deg(moon_sid_deg),
+            DashaSeed::Lagna => normalize_deg(asc_sid_deg),
+            DashaSeed::Stronger => {
+                let moon_sign = (normalize_deg(moon_sid_deg) / 30.0).floor() as u8 % 12;
+                if stronger_sign(chart, moon_sign, chart.lagna) == moon_sign {
+                    normalize_deg(moon_sid_deg)
+                } else {
+                    normalize_deg(asc_sid_deg)
+                }
+            }
+            DashaSeed::Kshema => from_moon(4.0),
+            DashaSeed::Utpanna => from_moon(5.0),
+            DashaSeed::Adhana => from_moon(8.0),
+        }
+    }
+}
+
+#[inline]
+fn normalize_deg(mut x: f64) -> f64 {
+    x %= 360.0;
+    if x < 0.0 {
+        x += 360.0;
+    }
+    x
+}
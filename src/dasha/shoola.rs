@@ -0,0 +1,40 @@
+// ======================================================
+// Shoola and Niryana Shoola Dasha (Jaimini)
+// ======================================================
+//
+// Both run 12 consecutive signs, zodiacally from an odd sign
+// and in reverse from an even one.
+// - Shoola         : from the stronger of lagna and 7th,
+//                    9 years per sign
+// - Niryana Shoola : from the stronger of 2nd and 8th,
+//                    movable 7, fixed 8, dual 9 years
+
+use crate::dasha::rashi::{
+    RashiAntarPeriod, RashiChart, RashiPeriod, equal_antardashas, is_odd, rashi_periods,
+    regular_sequence, sthira_years, stronger_sign,
+};
+use crate::dasha::year::DashaClock;
+
+pub fn shoola_dasha(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiPeriod> {
+    let start = stronger_sign(chart, chart.lagna, chart.lagna + 6);
+
+    let signs = regular_sequence(start, is_odd(start)).map(|sign| (sign, 9.0));
+    rashi_periods(clock, signs)
+}
+
+pub fn niryana_shoola_dasha(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiPeriod> {
+    let start = stronger_sign(chart, chart.lagna + 1, chart.lagna + 7);
+
+    let signs = regular_sequence(start, is_odd(start)).map(|sign| (sign, sthira_years(sign)));
+    rashi_periods(clock, signs)
+}
+
+/// 12 equal antardashas from the sign after the dasha sign,
+/// zodiacal for odd dasha signs
+pub fn shoola_antardashas(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiAntarPeriod> {
+    equal_antardashas(clock, &shoola_dasha(clock, chart), is_odd)
+}
+
+pub fn niryana_shoola_antardashas(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiAntarPeriod> {
+    equal_antardashas(clock, &niryana_shoola_dasha(clock, chart), is_odd)
+}
@@ -0,0 +1,60 @@
+// ======================================================
+// Sthira Dasha (Jaimini)
+// ======================================================
+//
+// Fixed lengths (movable 7, fixed 8, dual 9 years; 96 in all),
+// 12 consecutive signs from the sign of Brahma, zodiacally from
+// an odd sign and in reverse from an even one.
+
+use crate::astrology::houses::Sign;
+use crate::dasha::rashi::{
+    RashiAntarPeriod, RashiChart, RashiPeriod, count_signs, equal_antardashas, is_odd,
+    rashi_periods, regular_sequence, sthira_years, stronger_sign,
+};
+use crate::dasha::vimshottari::DashaLord;
+use crate::dasha::year::DashaClock;
+
+/// Brahma graha
+///
+/// Among the lords of the 6th, 8th and 12th from the stronger of
+/// lagna and 7th, those in odd signs are candidates, preferring
+/// ones in the 7th..12th houses from that sign. Ties go to the
+/// lord further advanced in its sign. Falls back to the lord of
+/// the 8th when no lord is in an odd sign.
+pub fn brahma(chart: &RashiChart) -> Option<DashaLord> {
+    let base = stronger_sign(chart, chart.lagna, chart.lagna + 6);
+    let lord_of = |n: u8| Sign::from_index(base + n - 1).lord();
+
+    let candidates: Vec<_> = [lord_of(6), lord_of(8), lord_of(12)]
+        .into_iter()
+        .filter_map(|l| chart.placement(l))
+        .filter(|p| is_odd(p.sign))
+        .collect();
+
+    let in_upper_half = |sign: u8| count_signs(base, sign, true) >= 7;
+
+    candidates
+        .iter()
+        .max_by(|a, b| {
+            in_upper_half(a.sign)
+                .cmp(&in_upper_half(b.sign))
+                .then(a.degree.total_cmp(&b.degree))
+        })
+        .map(|p| p.planet)
+        .or(Some(lord_of(8)))
+}
+
+pub fn sthira_dasha(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiPeriod> {
+    let start = brahma(chart)
+        .and_then(|b| chart.sign_of(b))
+        .unwrap_or(chart.lagna);
+
+    let signs = regular_sequence(start, is_odd(start)).map(|sign| (sign, sthira_years(sign)));
+    rashi_periods(clock, signs)
+}
+
+/// 12 equal antardashas from the sign after the dasha sign,
+/// zodiacal for odd dasha signs
+pub fn sthira_antardashas(clock: &DashaClock, chart: &RashiChart) -> Vec<RashiAntarPeriod> {
+    equal_antardashas(clock, &sthira_dasha(clock, chart), is_odd)
+}
@@ -2,15 +2,21 @@
 // Vimshottari Dasha — JHora Correct Implementation
 // ======================================================
 
-use crate::{astrology::nakshatra_calc::compute_nakshatra, dasha::DashaMode};
+use crate::dasha::{
+    nakshatra_dasha::{
+        NakshatraDasha, NakshatraMapping, nakshatra_antardasha_timeline,
+        nakshatra_mahadasha_timeline, nakshatra_pratyantardasha_timeline,
+    },
+    year::DashaClock,
+};
 
 // ------------------ CONSTANTS ------------------
 
 // Sidereal year (days) — JHora compatible
-const SIDEREAL_YEAR: f64 = 365.256363004;
+pub const SIDEREAL_YEAR: f64 = 365.256363004;
 
-// Full Vimshottari cycle = 120 sidereal years
-const TOTAL_DAYS: f64 = 120.0 * SIDEREAL_YEAR;
+// Full Vimshottari cycle = 120 years
+const CYCLE_YEARS: f64 = 120.0;
 
 // Nakshatra length (degrees)
 const _NAK_LEN: f64 = 13.333333333333334;
@@ -45,11 +51,28 @@ impl DashaLord {
             Mercury => Ketu,
         }
     }
+
+    /// Parse a planet name as used in `PlanetJS` ("Sun", "Rahu", ...)
+    pub fn from_name(name: &str) -> Option<Self> {
+        use DashaLord::*;
+        match name {
+            "Ketu" => Some(Ketu),
+            "Venus" => Some(Venus),
+            "Sun" => Some(Sun),
+            "Moon" => Some(Moon),
+            "Mars" => Some(Mars),
+            "Rahu" => Some(Rahu),
+            "Jupiter" => Some(Jupiter),
+            "Saturn" => Some(Saturn),
+            "Mercury" => Some(Mercury),
+            _ => None,
+        }
+    }
 }
 
 // ------------------ MAHĀDASHĀ YEARS ------------------
 
-fn dasha_years(l: DashaLord) -> f64 {
+pub fn dasha_years(l: DashaLord) -> f64 {
     use DashaLord::*;
     match l {
         Ketu => 7.0,
@@ -64,6 +87,28 @@ fn dasha_years(l: DashaLord) -> f64 {
     }
 }
 
+/// Vimshottari as a table for the nakshatra dasha engine:
+/// counted from Ashwini, whose lord is Ketu
+pub const VIMSHOTTARI: NakshatraDasha = NakshatraDasha {
+    name: "Vimshottari",
+    lords: &[
+        (DashaLord::Ketu, 7.0),
+        (DashaLord::Venus, 20.0),
+        (DashaLord::Sun, 6.0),
+        (DashaLord::Moon, 10.0),
+        (DashaLord::Mars, 7.0),
+        (DashaLord::Rahu, 18.0),
+        (DashaLord::Jupiter, 16.0),
+        (DashaLord::Saturn, 19.0),
+        (DashaLord::Mercury, 17.0),
+    ],
+    mapping: NakshatraMapping::Count {
+        from: 0,
+        reverse: false,
+    },
+    cycle_years: CYCLE_YEARS,
+};
+
 // ------------------ PERIOD STRUCTS ------------------
 
 #[derive(Clone)]
@@ -71,7 +116,6 @@ pub struct DashaPeriod {
     pub lord: DashaLord,
     pub start_jd: f64,
     pub end_jd: f64,
-    pub mode: DashaMode,
 }
 
 #[derive(Clone)]
@@ -80,7 +124,6 @@ pub struct AntarPeriod {
     pub antara: DashaLord,
     pub start_jd: f64,
     pub end_jd: f64,
-    pub mode: DashaMode,
 }
 
 #[derive(Clone)]
@@ -90,42 +133,14 @@ pub struct PratyPeriod {
     pub praty: DashaLord,
     pub start_jd: f64,
     pub end_jd: f64,
-    pub mode: DashaMode,
 }
 
 // ======================================================
 // 1️⃣ MAHĀDASHĀ — JHora BACKWARD-SHIFT METHOD
 // ======================================================
 
-pub fn mahadasha_timeline(birth_jd: f64, moon_sid_deg: f64, mode: DashaMode) -> Vec<DashaPeriod> {
-    let nak = compute_nakshatra(moon_sid_deg, mode);
-
-    let start_lord = nak.lord;
-    let frac_elapsed = nak.fraction_elapsed;
-
-    let elapsed_years = frac_elapsed * dasha_years(start_lord);
-    let elapsed_days = elapsed_years * SIDEREAL_YEAR;
-
-    let mut current_jd = birth_jd - elapsed_days;
-    let mut lord = start_lord;
-
-    let mut out = Vec::new();
-
-    for _ in 0..9 {
-        let days = dasha_years(lord) * SIDEREAL_YEAR;
-
-        out.push(DashaPeriod {
-            lord,
-            start_jd: current_jd,
-            end_jd: current_jd + days,
-            mode,
-        });
-
-        current_jd += days;
-        lord = lord.next();
-    }
-
-    out
+pub fn mahadasha_timeline(birth_jd: f64, seed_sid_deg: f64) -> Vec<DashaPeriod> {
+    nakshatra_mahadasha_timeline(&VIMSHOTTARI, &DashaClock::sidereal(birth_jd), seed_sid_deg)
 }
 
 // pub fn mahadasha_timeline(birth_jd: f64, moon_sid_deg: f64) -> Vec<DashaPeriod> {
@@ -168,68 +183,14 @@ pub fn mahadasha_timeline(birth_jd: f64, moon_sid_deg: f64, mode: DashaMode) ->
 // 2️⃣ ANTARDASHĀ — SINGLE BASE CYCLE (CRITICAL FIX)
 // ======================================================
 
-pub fn antardasha_timeline(birth_jd: f64, moon_sid_deg: f64, mode: DashaMode) -> Vec<AntarPeriod> {
-    let maha = mahadasha_timeline(birth_jd, moon_sid_deg, mode);
-    let mut out = Vec::new();
-
-    for m in maha {
-        let mut cur = m.start_jd;
-        let mut antara = m.lord;
-
-        for _ in 0..9 {
-            let days = TOTAL_DAYS * (dasha_years(m.lord) / 120.0) * (dasha_years(antara) / 120.0);
-
-            out.push(AntarPeriod {
-                maha: m.lord,
-                antara,
-                start_jd: cur,
-                end_jd: cur + days,
-                mode,
-            });
-
-            cur += days;
-            antara = antara.next();
-        }
-    }
-
-    out
+pub fn antardasha_timeline(birth_jd: f64, seed_sid_deg: f64) -> Vec<AntarPeriod> {
+    nakshatra_antardasha_timeline(&VIMSHOTTARI, &DashaClock::sidereal(birth_jd), seed_sid_deg)
 }
 
 // ======================================================
 // 3️⃣ PRATYANTARDASHĀ — SINGLE BASE CYCLE (CRITICAL FIX)
 // ======================================================
 
-pub fn pratyantardasha_timeline(
-    birth_jd: f64,
-    moon_sid_deg: f64,
-    mode: DashaMode,
-) -> Vec<PratyPeriod> {
-    let antara = antardasha_timeline(birth_jd, moon_sid_deg, mode);
-    let mut out = Vec::new();
-
-    for a in antara {
-        let mut cur = a.start_jd;
-        let mut praty = a.antara;
-
-        for _ in 0..9 {
-            let days = TOTAL_DAYS
-                * (dasha_years(a.maha) / 120.0)
-                * (dasha_years(a.antara) / 120.0)
-                * (dasha_years(praty) / 120.0);
-
-            out.push(PratyPeriod {
-                maha: a.maha,
-                antara: a.antara,
-                praty,
-                start_jd: cur,
-                end_jd: cur + days,
-                mode,
-            });
-
-            cur += days;
-            praty = praty.next();
-        }
-    }
-
-    out
+pub fn pratyantardasha_timeline(birth_jd: f64, seed_sid_deg: f64) -> Vec<PratyPeriod> {
+    nakshatra_pratyantardasha_timeline(&VIMSHOTTARI, &DashaClock::sidereal(birth_jd), seed_sid_deg)
 }
@@ -0,0 +1,226 @@
+// ======================================================
+// Dasha Year Length
+// ======================================================
+//
+// Dasha periods are given in years; traditions differ on how
+// long a year is. Fixed-length years are a day count. The true
+// solar return year ends each time the Sun comes back to its
+// natal sidereal longitude; fractions of a year follow the
+// Sun's sidereal motion (1 year = 360° of Sun). The Sun is made
+// sidereal with the chart's ayanamsa and nutation settings.
+
+use crate::compat::Compatibility;
+use crate::dasha::vimshottari::SIDEREAL_YEAR;
+use crate::ephemeris::planets::sun_lon;
+use crate::ephemeris::precession::general_precession_lon;
+
+// ------------------ CONSTANTS ------------------
+
+const SAVANA_YEAR: f64 = 360.0;
+const JULIAN_YEAR: f64 = 365.25;
+const TROPICAL_YEAR: f64 = 365.242_190;
+const GREGORIAN_YEAR: f64 = 365.2425;
+
+// Root search on the Sun's longitude
+const MAX_ITER: usize = 10;
+const TOLERANCE_DAYS: f64 = 1e-6;
+
+/// Length of a dasha year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashaYear {
+    /// 360-day savana year
+    Savana,
+    /// 365.25 days
+    Julian,
+    /// 365.24219 days
+    Tropical,
+    /// 365.2425 days
+    Gregorian,
+    /// 365.256363 days (JHora default)
+    #[default]
+    Sidereal,
+    /// Sun's return to its natal sidereal longitude
+    SolarReturn,
+}
+
+impl DashaYear {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DashaYear::Savana => "Savana",
+            DashaYear::Julian => "Julian",
+            DashaYear::Tropical => "Tropical",
+            DashaYear::Gregorian => "Gregorian",
+            DashaYear::Sidereal => "Sidereal",
+            DashaYear::SolarReturn => "SolarReturn",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Savana" => Some(DashaYear::Savana),
+            "Julian" => Some(DashaYear::Julian),
+            "Tropical" => Some(DashaYear::Tropical),
+            "Gregorian" => Some(DashaYear::Gregorian),
+            "Sidereal" => Some(DashaYear::Sidereal),
+            "SolarReturn" => Some(DashaYear::SolarReturn),
+            _ => None,
+        }
+    }
+
+    /// Days in a year (mean length for the solar return year)
+    pub fn days(&self) -> f64 {
+        match self {
+            DashaYear::Savana => SAVANA_YEAR,
+            DashaYear::Julian => JULIAN_YEAR,
+            DashaYear::Tropical => TROPICAL_YEAR,
+            DashaYear::Gregorian => GREGORIAN_YEAR,
+            DashaYear::Sidereal | DashaYear::SolarReturn => SIDEREAL_YEAR,
+        }
+    }
+}
+
+/// A span of dasha years as whole years, months (1/12 year)
+/// and days of that month
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YearsMonthsDays {
+    pub years: u32,
+    pub months: u32,
+    pub days: f64,
+}
+
+impl YearsMonthsDays {
+    /// Split `years` (negative counts as 0) using the day count
+    /// of a `year`-long month
+    pub fn from_years(years: f64, year: DashaYear) -> Self {
+        let years = years.max(0.0);
+        let months = years.fract() * 12.0;
+
+        YearsMonthsDays {
+            years: years.trunc() as u32,
+            months: months.trunc() as u32,
+            days: months.fract() * year.days() / 12.0,
+        }
+    }
+}
+
+// ======================================================
+// CLOCK
+// ======================================================
+
+/// Converts dasha years counted from birth to Julian days
+#[derive(Debug, Clone, Copy)]
+pub struct DashaClock {
+    pub birth_jd: f64,
+    pub year: DashaYear,
+    /// Ayanamsa and nutation of the Sun for solar return years
+    compat: Compatibility,
+    /// Natal sidereal Sun (degrees), for solar return years
+    natal_sun: f64,
+}
+
+impl DashaClock {
+    /// Clock with this crate's own conventions
+    pub fn new(birth_jd: f64, year: DashaYear) -> Self {
+        Self::with_compat(birth_jd, year, &Compatibility::ASTRONOMICAL)
+    }
+
+    /// Clock whose solar return Sun follows a chart's settings
+    pub fn with_compat(birth_jd: f64, year: DashaYear, compat: &Compatibility) -> Self {
+        let natal_sun = match year {
+            DashaYear::SolarReturn => sun_sidereal_deg(birth_jd, compat),
+            _ => 0.0,
+        };
+
+        DashaClock {
+            birth_jd,
+            year,
+            compat: *compat,
+            natal_sun,
+        }
+    }
+
+    /// Clock with the default (sidereal) year
+    pub fn sidereal(birth_jd: f64) -> Self {
+        Self::new(birth_jd, DashaYear::Sidereal)
+    }
+
+    /// JD at `years` after birth (negative: before birth)
+    pub fn jd(&self, years: f64) -> f64 {
+        let guess = self.birth_jd + years * self.year.days();
+
+        match self.year {
+            DashaYear::SolarReturn => self.solar_jd(years, guess),
+            _ => guess,
+        }
+    }
+
+    /// Dasha years from birth to `jd` (inverse of `jd`)
+    pub fn years(&self, jd: f64) -> f64 {
+        let mean = (jd - self.birth_jd) / self.year.days();
+
+        match self.year {
+            DashaYear::SolarReturn => mean + self.solar_error(mean, jd) / 360.0,
+            _ => mean,
+        }
+    }
+
+    /// End of a period of `years` starting at `start_jd`
+    pub fn advance(&self, start_jd: f64, years: f64) -> f64 {
+        match self.year {
+            DashaYear::SolarReturn => {
+                let guess = start_jd + years * self.year.days();
+                self.solar_jd(self.years(start_jd) + years, guess)
+            }
+            _ => start_jd + years * self.year.days(),
+        }
+    }
+
+    /// Sun's sidereal position at `jd` less its position after
+    /// `years` of solar motion, in (-180, 180]
+    fn solar_error(&self, years: f64, jd: f64) -> f64 {
+        let target = self.natal_sun + years.fract() * 360.0;
+        signed_deg(sun_sidereal_deg(jd, &self.compat) - target)
+    }
+
+    /// Newton search with the Sun's mean motion as derivative
+    fn solar_jd(&self, years: f64, guess: f64) -> f64 {
+        let rate = 360.0 / SIDEREAL_YEAR;
+        let mut jd = guess;
+
+        for _ in 0..MAX_ITER {
+            let step = self.solar_error(years, jd) / rate;
+            jd -= step;
+            if step.abs() < TOLERANCE_DAYS {
+                break;
+            }
+        }
+
+        jd
+    }
+}
+
+/// Sidereal longitude of the Sun in degrees
+///
+/// `sun_lon` is referred to the J2000 equinox; the general
+/// precession carries it to the mean equinox of date that the
+/// ayanamsa is measured from.
+fn sun_sidereal_deg(jd_tt: f64, compat: &Compatibility) -> f64 {
+    let sun_of_date = sun_lon(jd_tt) + general_precession_lon(jd_tt);
+    normalize_deg(compat.sidereal(sun_of_date, jd_tt).to_degrees())
+}
+
+#[inline]
+fn normalize_deg(mut x: f64) -> f64 {
+    x %= 360.0;
+    if x < 0.0 {
+        x += 360.0;
+    }
+    x
+}
+
+/// Angle in (-180, 180]
+#[inline]
+fn signed_deg(x: f64) -> f64 {
+    let x = normalize_deg(x);
+    if x > 180.0 { x - 360.0 } else { x }
+}
@@ -0,0 +1,233 @@
+// ======================================================
+// Yogini Dasha — 8 yoginis, 36-year cycle
+// ======================================================
+
+use crate::{
+    astrology::nakshatra_calc::compute_nakshatra,
+    dasha::{vimshottari::DashaLord, year::DashaClock},
+};
+
+// ------------------ CONSTANTS ------------------
+
+// One Yogini cycle = 36 years
+const CYCLE_YEARS: f64 = 36.0;
+
+// Mahadasha cycles emitted (3 × 36 = 108 years)
+const CYCLES: usize = 3;
+
+// ------------------ YOGINIS ------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Yogini {
+    Mangala,
+    Pingala,
+    Dhanya,
+    Bhramari,
+    Bhadrika,
+    Ulka,
+    Siddha,
+    Sankata,
+}
+
+impl Yogini {
+    pub fn next(self) -> Self {
+        use Yogini::*;
+        match self {
+            Mangala => Pingala,
+            Pingala => Dhanya,
+            Dhanya => Bhramari,
+            Bhramari => Bhadrika,
+            Bhadrika => Ulka,
+            Ulka => Siddha,
+            Siddha => Sankata,
+            Sankata => Mangala,
+        }
+    }
+
+    /// Yogini from its number (1 = Mangala .. 8 = Sankata)
+    pub fn from_number(n: usize) -> Self {
+        use Yogini::*;
+        match (n + 7) % 8 {
+            0 => Mangala,
+            1 => Pingala,
+            2 => Dhanya,
+            3 => Bhramari,
+            4 => Bhadrika,
+            5 => Ulka,
+            6 => Siddha,
+            _ => Sankata,
+        }
+    }
+
+    /// Dasha years (also the yogini's number)
+    pub fn years(self) -> f64 {
+        use Yogini::*;
+        match self {
+            Mangala => 1.0,
+            Pingala => 2.0,
+            Dhanya => 3.0,
+            Bhramari => 4.0,
+            Bhadrika => 5.0,
+            Ulka => 6.0,
+            Siddha => 7.0,
+            Sankata => 8.0,
+        }
+    }
+
+    /// Ruling planet
+    pub fn lord(self) -> DashaLord {
+        use Yogini::*;
+        match self {
+            Mangala => DashaLord::Moon,
+            Pingala => DashaLord::Sun,
+            Dhanya => DashaLord::Jupiter,
+            Bhramari => DashaLord::Mars,
+            Bhadrika => DashaLord::Mercury,
+            Ulka => DashaLord::Saturn,
+            Siddha => DashaLord::Venus,
+            Sankata => DashaLord::Rahu,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        use Yogini::*;
+        match self {
+            Mangala => "Mangala",
+            Pingala => "Pingala",
+            Dhanya => "Dhanya",
+            Bhramari => "Bhramari",
+            Bhadrika => "Bhadrika",
+            Ulka => "Ulka",
+            Siddha => "Siddha",
+            Sankata => "Sankata",
+        }
+    }
+}
+
+// ------------------ PERIOD STRUCTS ------------------
+
+#[derive(Clone)]
+pub struct YoginiPeriod {
+    pub yogini: Yogini,
+    pub start_jd: f64,
+    pub end_jd: f64,
+}
+
+#[derive(Clone)]
+pub struct YoginiAntarPeriod {
+    pub maha: Yogini,
+    pub antara: Yogini,
+    pub start_jd: f64,
+    pub end_jd: f64,
+}
+
+#[derive(Clone)]
+pub struct YoginiPratyPeriod {
+    pub maha: Yogini,
+    pub antara: Yogini,
+    pub praty: Yogini,
+    pub start_jd: f64,
+    pub end_jd: f64,
+}
+
+// ======================================================
+// 1️⃣ MAHĀDASHĀ
+// ======================================================
+
+/// Starting yogini: (janma nakshatra number + 3) mod 8
+pub fn starting_yogini(nakshatra_index: usize) -> Yogini {
+    Yogini::from_number(nakshatra_index + 1 + 3)
+}
+
+pub fn yogini_mahadasha_timeline(clock: &DashaClock, moon_sid_deg: f64) -> Vec<YoginiPeriod> {
+    let nak = compute_nakshatra(moon_sid_deg);
+
+    let start = starting_yogini(nak.index);
+    let elapsed_years = nak.fraction_elapsed * start.years();
+
+    let mut current_jd = clock.jd(-elapsed_years);
+    let mut yogini = start;
+
+    let mut out = Vec::new();
+
+    for _ in 0..8 * CYCLES {
+        let end_jd = clock.advance(current_jd, yogini.years());
+
+        out.push(YoginiPeriod {
+            yogini,
+            start_jd: current_jd,
+            end_jd,
+        });
+
+        current_jd = end_jd;
+        yogini = yogini.next();
+    }
+
+    out
+}
+
+// ======================================================
+// 2️⃣ ANTARDASHĀ
+// ======================================================
+
+pub fn yogini_antardasha_timeline(clock: &DashaClock, moon_sid_deg: f64) -> Vec<YoginiAntarPeriod> {
+    let maha = yogini_mahadasha_timeline(clock, moon_sid_deg);
+    let mut out = Vec::new();
+
+    for m in maha {
+        let mut cur = m.start_jd;
+        let mut antara = m.yogini;
+
+        for _ in 0..8 {
+            let end_jd = clock.advance(cur, m.yogini.years() * (antara.years() / CYCLE_YEARS));
+
+            out.push(YoginiAntarPeriod {
+                maha: m.yogini,
+                antara,
+                start_jd: cur,
+                end_jd,
+            });
+
+            cur = end_jd;
+            antara = antara.next();
+        }
+    }
+
+    out
+}
+
+// ======================================================
+// 3️⃣ PRATYANTARDASHĀ
+// ======================================================
+
+pub fn yogini_pratyantardasha_timeline(
+    clock: &DashaClock,
+    moon_sid_deg: f64,
+) -> Vec<YoginiPratyPeriod> {
+    let antara = yogini_antardasha_timeline(clock, moon_sid_deg);
+    let mut out = Vec::new();
+
+    for a in antara {
+        let mut cur = a.start_jd;
+        let mut praty = a.antara;
+
+        for _ in 0..8 {
+            let years =
+                a.maha.years() * (a.antara.years() / CYCLE_YEARS) * (praty.years() / CYCLE_YEARS);
+            let end_jd = clock.advance(cur, years);
+
+            out.push(YoginiPratyPeriod {
+                maha: a.maha,
+                antara: a.antara,
+                praty,
+                start_jd: cur,
+                end_jd,
+            });
+
+            cur = end_jd;
+            praty = praty.next();
+        }
+    }
+
+    out
+}
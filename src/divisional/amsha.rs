@@ -0,0 +1,167 @@
+// src/divisional/amsha.rs
+//
+// Amsha (division) index, traditional names and nature
+// for the higher vargas (BPHS ch. 6).
+//
+// Longitudes are SIDEREAL DEGREES.
+
+/// Benefic / malefic classification of an amsha
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmshaNature {
+    Benefic,
+    Malefic,
+}
+
+impl AmshaNature {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AmshaNature::Benefic => "Benefic",
+            AmshaNature::Malefic => "Malefic",
+        }
+    }
+}
+
+/// Amsha occupied by a longitude in a given varga
+#[derive(Debug, Clone, Copy)]
+pub struct Amsha {
+    pub division: u8,
+    pub index: u16, // 1..=division, in traditional counting order
+    pub name: Option<&'static str>,
+    pub nature: Option<AmshaNature>,
+}
+
+use AmshaNature::{Benefic as B, Malefic as M};
+
+/// Shashtiamsa (D60) deities, odd-sign order
+pub const SHASHTIAMSA: [(&str, AmshaNature); 60] = [
+    ("Ghora", M),
+    ("Rakshasa", M),
+    ("Deva", B),
+    ("Kubera", B),
+    ("Yaksha", B),
+    ("Kinnara", B),
+    ("Bhrashta", M),
+    ("Kulaghna", M),
+    ("Garala", M),
+    ("Vahni", M),
+    ("Maya", M),
+    ("Purishaka", M),
+    ("Apampati", B),
+    ("Marutvan", B),
+    ("Kala", M),
+    ("Sarpa", M),
+    ("Amrita", B),
+    ("Indu", B),
+    ("Mridu", B),
+    ("Komala", B),
+    ("Heramba", B),
+    ("Brahma", B),
+    ("Vishnu", B),
+    ("Maheshvara", B),
+    ("Deva", B),
+    ("Ardra", M),
+    ("Kalinasha", B),
+    ("Kshitisha", B),
+    ("Kamalakara", B),
+    ("Gulika", M),
+    ("Mrityu", M),
+    ("Kala", M),
+    ("Davagni", M),
+    ("Ghora", M),
+    ("Yama", M),
+    ("Kantaka", M),
+    ("Sudha", B),
+    ("Amrita", B),
+    ("Purnachandra", B),
+    ("Vishadagdha", M),
+    ("Kulanasha", M),
+    ("Vamshakshaya", M),
+    ("Utpata", M),
+    ("Kala", M),
+    ("Saumya", B),
+    ("Komala", B),
+    ("Shitala", B),
+    ("Karaladamshtra", M),
+    ("Chandramukhi", B),
+    ("Pravina", B),
+    ("Kalapavaka", M),
+    ("Dandayudha", M),
+    ("Nirmala", B),
+    ("Saumya", B),
+    ("Krura", M),
+    ("Atishitala", B),
+    ("Amrita", B),
+    ("Payodhi", B),
+    ("Bhramana", M),
+    ("Chandrarekha", B),
+];
+
+/// Khavedamsa (D40) lords, repeating every 12 amshas
+pub const KHAVEDAMSA: [&str; 12] = [
+    "Vishnu",
+    "Chandra",
+    "Marichi",
+    "Tvashta",
+    "Dhata",
+    "Shiva",
+    "Ravi",
+    "Yama",
+    "Yaksha",
+    "Gandharva",
+    "Kala",
+    "Varuna",
+];
+
+/// Akshavedamsa (D45) lords, repeating every 3 amshas
+/// (movable signs; fixed start from Shiva, dual from Vishnu)
+pub const AKSHAVEDAMSA: [&str; 3] = ["Brahma", "Shiva", "Vishnu"];
+
+/// Amsha of a sidereal longitude (degrees) in division `division`
+///
+/// The index follows the traditional counting order:
+/// - D60  : even signs count the deities in reverse
+/// - D150 : movable signs forward, fixed signs in reverse,
+///   dual signs starting from the 76th nadi
+///
+/// Names and nature are given for D40, D45 and D60.
+pub fn amsha(division: u8, lon_deg: f64) -> Amsha {
+    let n = division.max(1) as u16;
+
+    let mut lon = lon_deg % 360.0;
+    if lon < 0.0 {
+        lon += 360.0;
+    }
+
+    let sign = (lon / 30.0).floor() as u16 % 12;
+    let deg = lon - sign as f64 * 30.0;
+    let p = ((deg / (30.0 / n as f64)).floor() as u16).min(n - 1);
+
+    let odd = sign.is_multiple_of(2);
+
+    let index = match (division, sign % 3) {
+        (60, _) if !odd => n - p,
+        (150, 1) => n - p,
+        (150, 2) => (p + 75) % n + 1,
+        _ => p + 1,
+    };
+
+    let (name, nature) = match division {
+        60 => {
+            let (name, nature) = SHASHTIAMSA[index as usize - 1];
+            (Some(name), Some(nature))
+        }
+        40 => (Some(KHAVEDAMSA[(index as usize - 1) % 12]), None),
+        45 => {
+            let shift = (sign % 3) as usize;
+            (Some(AKSHAVEDAMSA[(index as usize - 1 + shift) % 3]), None)
+        }
+        _ => (None, None),
+    };
+
+    Amsha {
+        division,
+        index,
+        name,
+        nature,
+    }
+}
@@ -0,0 +1,103 @@
+// src/divisional/custom.rs
+//
+// User-registered varga mapping tables.
+//
+// A table lists, for every rashi sign and every equal amsha of
+// that sign, the varga sign it maps to:
+//
+//     table[sign * division + amsha] = varga sign (0 = Aries)
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::divisional::divisional::{VargaScheme, is_supported_division};
+
+/// A custom varga mapping table
+#[derive(Debug, Clone)]
+pub struct VargaTable {
+    pub name: String,
+    pub division: u8,
+    pub signs: Vec<u8>,
+}
+
+/// Why a custom table was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VargaTableError {
+    UnsupportedDivision,
+    /// `signs.len()` must be `12 * division`
+    WrongLength,
+    /// Every entry must be a sign index 0..11
+    InvalidSign,
+}
+
+static TABLES: Mutex<Vec<VargaTable>> = Mutex::new(Vec::new());
+
+/// Lock the registry, recovering it after a panic elsewhere
+///
+/// Every update is a single assignment or push, so a poisoned
+/// registry is still consistent.
+fn tables() -> MutexGuard<'static, Vec<VargaTable>> {
+    TABLES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Register a custom mapping table, returning its scheme
+///
+/// Registering a name again replaces the previous table.
+pub fn register_varga_table(
+    name: &str,
+    division: u8,
+    signs: Vec<u8>,
+) -> Result<VargaScheme, VargaTableError> {
+    if !is_supported_division(division) {
+        return Err(VargaTableError::UnsupportedDivision);
+    }
+    if signs.len() != 12 * division as usize {
+        return Err(VargaTableError::WrongLength);
+    }
+    if signs.iter().any(|&s| s >= 12) {
+        return Err(VargaTableError::InvalidSign);
+    }
+
+    let mut tables = tables();
+    let table = VargaTable {
+        name: name.to_string(),
+        division,
+        signs,
+    };
+
+    let id = match tables.iter().position(|t| t.name == name) {
+        Some(i) => {
+            tables[i] = table;
+            i
+        }
+        None => {
+            tables.push(table);
+            tables.len() - 1
+        }
+    };
+
+    Ok(VargaScheme::Custom(id as u16))
+}
+
+/// Look up a registered table by name
+pub fn custom_scheme(name: &str) -> Option<VargaScheme> {
+    tables()
+        .iter()
+        .position(|t| t.name == name)
+        .map(|i| VargaScheme::Custom(i as u16))
+}
+
+/// Varga sign from a registered table
+///
+/// `None` if the id is unknown or the table is for another division.
+pub(crate) fn custom_sign(id: u16, division: u8, sign: u8, amsha: u8) -> Option<u8> {
+    let tables = tables();
+    let t = tables.get(id as usize)?;
+
+    if t.division != division {
+        return None;
+    }
+
+    t.signs
+        .get(sign as usize * division as usize + amsha as usize)
+        .copied()
+}
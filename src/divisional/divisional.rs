@@ -1,17 +1,28 @@
 use std::f64::consts::TAU;
 
+use crate::astrology::houses::{House, whole_sign_houses};
+use crate::divisional::amsha::{Amsha, amsha};
+use crate::divisional::custom::{custom_scheme, custom_sign};
+
 /// Output planet in a divisional chart
 #[derive(Clone)]
 pub struct DivisionalPlanet {
     pub planet: String,
     pub sign: String,
     pub degree: f64,
+    pub amsha: Amsha,
 }
 
 /// Output divisional chart
 pub struct DivisionalChart {
     pub division: u8,
+    /// Varga lagna; `None` when built without an ascendant
+    pub ascendant: Option<DivisionalPlanet>,
+    /// Whole-sign houses from the varga lagna (empty without one)
+    pub houses: Vec<House>,
     pub planets: Vec<DivisionalPlanet>,
+    /// Special lagnas / upagrahas placed in this varga
+    pub special_points: Vec<DivisionalPlanet>,
 }
 
 static SIGNS: [&str; 12] = [
@@ -34,30 +45,339 @@ fn sign_name(index: u8) -> &'static str {
     SIGNS[(index % 12) as usize]
 }
 
-/// Generic Parāśari divisional mapping (D1–D30)
-pub fn divisional_chart(division: u8, planets: &[crate::PlanetJS]) -> DivisionalChart {
-    assert!(division >= 1 && division <= 30);
+/// Varga mapping scheme
+///
+/// Division-specific schemes (e.g. `JagannathaDrekkana`) only
+/// change their own division; other divisions use Parāśari.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VargaScheme {
+    /// Classical BPHS rule per division
+    Parashari,
+    /// Linear (cyclic) mapping: `floor(lon / (30° / n)) % 12`.
+    /// For D3 this is the Parivritti-traya drekkana.
+    Parivritti,
+    /// D3: from the movable sign of the triplicity, then its 5th and 9th
+    JagannathaDrekkana,
+    /// D3: odd signs counted forward from Aries, even signs
+    /// backward from Pisces, continuing across the zodiac
+    SomanathaDrekkana,
+    /// D2: the two signs of the rashi lord, odd sign first in odd
+    /// rashis; the luminaries share Leo and Cancer
+    KashinathaHora,
+    /// Registered table, see `register_varga_table`
+    Custom(u16),
+}
+
+impl VargaScheme {
+    /// Parse a scheme name, including registered custom tables
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Parashari" => Some(VargaScheme::Parashari),
+            "Parivritti" | "ParivrittiTraya" => Some(VargaScheme::Parivritti),
+            "Jagannatha" | "JagannathaDrekkana" => Some(VargaScheme::JagannathaDrekkana),
+            "Somanatha" | "SomanathaDrekkana" => Some(VargaScheme::SomanathaDrekkana),
+            "Kashinatha" | "KashinathaHora" => Some(VargaScheme::KashinathaHora),
+            _ => custom_scheme(name),
+        }
+    }
+}
 
-    let mut out = Vec::new();
-    let part = TAU / (12.0 * division as f64);
+/// Scheme choice per division (Parāśari unless overridden)
+#[derive(Debug, Clone, Default)]
+pub struct VargaSchemes {
+    overrides: Vec<(u8, VargaScheme)>,
+}
 
-    for p in planets {
-        let lon = p.sidereal_deg.to_radians();
+impl VargaSchemes {
+    /// Use `scheme` for `division`, replacing any earlier choice
+    pub fn set(&mut self, division: u8, scheme: VargaScheme) {
+        self.overrides.retain(|(d, _)| *d != division);
+        self.overrides.push((division, scheme));
+    }
 
-        let index = (lon / part).floor() as u32;
-        let sign_index = (index % 12) as u8;
+    /// Scheme to use for `division`
+    pub fn get(&self, division: u8) -> VargaScheme {
+        self.overrides
+            .iter()
+            .find(|(d, _)| *d == division)
+            .map(|(_, s)| *s)
+            .unwrap_or(VargaScheme::Parashari)
+    }
+}
 
-        let deg_in_sign = ((lon / part).fract()) * (30.0 / division as f64);
+/// Is `division` supported by `divisional_chart`?
+///
+/// D1–D60, plus D81, D108, D144 and D150 (Nadi amsha).
+pub fn is_supported_division(division: u8) -> bool {
+    (1..=60).contains(&division) || matches!(division, 81 | 108 | 144 | 150)
+}
 
-        out.push(DivisionalPlanet {
-            planet: p.name.clone(),
-            sign: sign_name(sign_index).to_string(),
-            degree: deg_in_sign,
-        });
+/// Parāśari divisional chart
+pub fn divisional_chart(division: u8, planets: &[crate::PlanetJS]) -> DivisionalChart {
+    divisional_chart_with(division, planets, VargaScheme::Parashari)
+}
+
+/// Divisional chart with an explicit mapping scheme
+pub fn divisional_chart_with(
+    division: u8,
+    planets: &[crate::PlanetJS],
+    scheme: VargaScheme,
+) -> DivisionalChart {
+    assert!(is_supported_division(division));
+
+    DivisionalChart {
+        division,
+        ascendant: None,
+        houses: Vec::new(),
+        planets: place_all(division, planets, scheme),
+        special_points: Vec::new(),
     }
+}
+
+/// Divisional chart with its own lagna and whole-sign houses
+///
+/// Inputs:
+/// - asc_sid_deg    : sidereal D1 ascendant (degrees)
+/// - special_points : extra points (special lagnas, upagrahas)
+///   mapped into the varga like planets
+pub fn divisional_chart_with_lagna(
+    division: u8,
+    planets: &[crate::PlanetJS],
+    asc_sid_deg: f64,
+    special_points: &[crate::PlanetJS],
+    scheme: VargaScheme,
+) -> DivisionalChart {
+    assert!(is_supported_division(division));
+
+    let (asc_sign, asc_deg) = varga_position(division, asc_sid_deg, scheme);
+    let ascendant = DivisionalPlanet {
+        planet: "Ascendant".to_string(),
+        sign: sign_name(asc_sign).to_string(),
+        degree: asc_deg,
+        amsha: amsha(division, asc_sid_deg),
+    };
+
+    // Middle of the lagna's varga sign, clear of boundary rounding
+    let houses = whole_sign_houses((asc_sign as f64 + 0.5) * TAU / 12.0);
 
     DivisionalChart {
         division,
-        planets: out,
+        ascendant: Some(ascendant),
+        houses,
+        planets: place_all(division, planets, scheme),
+        special_points: place_all(division, special_points, scheme),
+    }
+}
+
+fn place_all(
+    division: u8,
+    points: &[crate::PlanetJS],
+    scheme: VargaScheme,
+) -> Vec<DivisionalPlanet> {
+    points
+        .iter()
+        .map(|p| {
+            let (sign_index, deg_in_sign) = varga_position(division, p.sidereal_deg, scheme);
+
+            DivisionalPlanet {
+                planet: p.name.clone(),
+                sign: sign_name(sign_index).to_string(),
+                degree: deg_in_sign,
+                amsha: amsha(division, p.sidereal_deg),
+            }
+        })
+        .collect()
+}
+
+/// Varga sign of a sidereal longitude (degrees)
+///
+/// Returns (sign index 0..11, offset inside the amsa in degrees).
+///
+/// Parāśari rules (BPHS), by rashi sign:
+/// - D2  Hora         : odd Leo→Cancer, even Cancer→Leo
+/// - D3  Drekkana     : 1st, 5th, 9th
+/// - D4  Chaturthamsa : 1st, 4th, 7th, 10th
+/// - D7  Saptamsa     : odd from itself, even from the 7th
+/// - D9  Navamsa      : from the movable sign of the triplicity
+/// - D10 Dasamsa      : odd from itself, even from the 9th
+/// - D12 Dwadasamsa   : from itself
+/// - D16 Shodasamsa   : movable Aries, fixed Leo, dual Sagittarius
+/// - D20 Vimsamsa     : movable Aries, fixed Sagittarius, dual Leo
+/// - D24 Siddhamsa    : odd from Leo, even from Cancer
+/// - D27 Bhamsa       : fire Aries, earth Cancer, air Libra, water Capricorn
+/// - D30 Trimsamsa    : unequal 5/5/8/7/5° segments ruled by
+///   Mars, Saturn, Jupiter, Mercury, Venus (reversed for even signs)
+/// - D40 Khavedamsa   : odd from Aries, even from Libra
+/// - D45 Akshavedamsa : movable Aries, fixed Leo, dual Sagittarius
+/// - D60 Shashtiamsa  : from the sign itself
+///
+/// D81, D108, D144 and D150 are counted cyclically (Parivritti).
+/// Other divisions have no distinct BPHS rule and use Parivritti.
+pub fn varga_position(division: u8, lon_deg: f64, scheme: VargaScheme) -> (u8, f64) {
+    let lon = normalize_deg(lon_deg);
+    let n = division.max(1) as f64;
+
+    let sign = (lon / 30.0).floor() as u8 % 12;
+    let deg = lon - sign as f64 * 30.0;
+
+    let part = 30.0 / n;
+    let p = ((deg / part).floor() as u8).min(division.max(1) - 1);
+    let offset = deg - p as f64 * part;
+
+    let odd = sign.is_multiple_of(2); // Aries is an odd sign
+
+    match (scheme, division) {
+        (VargaScheme::Parivritti, _) => {
+            return (
+                ((sign as u32 * division as u32 + p as u32) % 12) as u8,
+                offset,
+            );
+        }
+        (VargaScheme::JagannathaDrekkana, 3) => {
+            // Movable sign of the same element: Aries, Capricorn, Libra, Cancer
+            let movable = [0, 9, 6, 3][(sign % 4) as usize];
+            return ((movable + 4 * p) % 12, offset);
+        }
+        (VargaScheme::SomanathaDrekkana, 3) => {
+            let count = (sign / 2) * 3 + p;
+            let varga = if odd { count % 12 } else { 11 - count % 12 };
+            return (varga, offset);
+        }
+        (VargaScheme::KashinathaHora, 2) => {
+            return (kashinatha_hora(sign, odd == (p == 0)), offset);
+        }
+        (VargaScheme::Custom(id), _) => {
+            if let Some(varga) = custom_sign(id, division, sign, p) {
+                return (varga, offset);
+            }
+        }
+        _ => {}
+    }
+
+    let start = match division {
+        1 => sign,
+        2 => {
+            // Sun's hora = Leo, Moon's hora = Cancer
+            return match (odd, p) {
+                (true, 0) | (false, 1) => (4, offset),
+                _ => (3, offset),
+            };
+        }
+        3 => return ((sign + 4 * p) % 12, offset),
+        4 => return ((sign + 3 * p) % 12, offset),
+        7 => {
+            if odd {
+                sign
+            } else {
+                sign + 6
+            }
+        }
+        10 => {
+            if odd {
+                sign
+            } else {
+                sign + 8
+            }
+        }
+        12 => sign,
+        16 => match sign % 3 {
+            0 => 0,
+            1 => 4,
+            _ => 8,
+        },
+        20 => match sign % 3 {
+            0 => 0,
+            1 => 8,
+            _ => 4,
+        },
+        24 => {
+            if odd {
+                4
+            } else {
+                3
+            }
+        }
+        27 => match sign % 4 {
+            0 => 0,
+            1 => 3,
+            2 => 6,
+            _ => 9,
+        },
+        30 => return trimsamsa(odd, deg),
+        40 => {
+            if odd {
+                0
+            } else {
+                6
+            }
+        }
+        45 => match sign % 3 {
+            0 => 0,
+            1 => 4,
+            _ => 8,
+        },
+        60 => sign,
+        // D9 and divisions without a separate rule
+        _ => {
+            return (
+                ((sign as u32 * division as u32 + p as u32) % 12) as u8,
+                offset,
+            );
+        }
+    };
+
+    ((start + p) % 12, offset)
+}
+
+/// Kashinatha hora: odd or even sign owned by the rashi lord
+fn kashinatha_hora(sign: u8, odd_half: bool) -> u8 {
+    // (odd sign, even sign) of each rashi's lord
+    let (odd_sign, even_sign) = match sign {
+        0 | 7 => (0, 7),   // Mars
+        1 | 6 => (6, 1),   // Venus
+        2 | 5 => (2, 5),   // Mercury
+        8 | 11 => (8, 11), // Jupiter
+        9 | 10 => (10, 9), // Saturn
+        _ => (4, 3),       // Sun / Moon
+    };
+
+    if odd_half { odd_sign } else { even_sign }
+}
+
+/// Unequal Trimsamsa segments (D30)
+fn trimsamsa(odd: bool, deg: f64) -> (u8, f64) {
+    // (segment end, odd-sign varga, even-sign varga); the same planet
+    // rules a segment, giving its odd sign in odd rashis and vice versa
+    const SEGMENTS: [(f64, u8, u8); 5] = [
+        (5.0, 0, 7),   // Mars: Aries | Scorpio
+        (10.0, 10, 9), // Saturn: Aquarius | Capricorn
+        (18.0, 8, 11), // Jupiter: Sagittarius | Pisces
+        (25.0, 2, 5),  // Mercury: Gemini | Virgo
+        (30.0, 6, 1),  // Venus: Libra | Taurus
+    ];
+
+    // Even signs run the segments in reverse: 5/7/8/5/5
+    let d = if odd { deg } else { 30.0 - deg };
+
+    let mut prev = 0.0;
+    for (end, odd_sign, even_sign) in SEGMENTS {
+        // Reversed segments are closed at their (forward) start
+        let inside = if odd { d < end } else { d <= end };
+        if inside || end == 30.0 {
+            let offset = if odd { d - prev } else { end - d };
+            return (if odd { odd_sign } else { even_sign }, offset);
+        }
+        prev = end;
+    }
+
+    unreachable!()
+}
+
+#[inline]
+fn normalize_deg(mut x: f64) -> f64 {
+    x %= 360.0;
+    if x < 0.0 {
+        x += 360.0;
     }
+    x
 }
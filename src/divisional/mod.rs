@@ -1,3 +1,12 @@
+pub mod amsha;
+pub mod custom;
 pub mod divisional;
+pub mod vimshopaka;
 
-pub use divisional::{DivisionalChart, DivisionalPlanet, divisional_chart};
+pub use amsha::{Amsha, AmshaNature, amsha};
+pub use custom::{VargaTable, VargaTableError, custom_scheme, register_varga_table};
+pub use divisional::{
+    DivisionalChart, DivisionalPlanet, VargaScheme, VargaSchemes, divisional_chart,
+    divisional_chart_with, divisional_chart_with_lagna, is_supported_division, varga_position,
+};
+pub use vimshopaka::{VargaDignity, VargaGroup, VimshopakaBala, is_vargottama, vimshopaka_bala};
@@ -0,0 +1,249 @@
+// src/divisional/vimshopaka.rs
+//
+// Vimshopaka bala (BPHS ch. 7): a 20-point strength built from
+// a planet's dignity across a group of vargas.
+//
+// Only the seven visible planets are scored; the nodes are skipped.
+
+use crate::PlanetJS;
+use crate::astrology::dignity::{
+    Dignity, Relation, compound_relation, dignity, natural_relation, temporary_friend,
+};
+use crate::astrology::houses::Sign;
+use crate::dasha::vimshottari::DashaLord;
+use crate::divisional::divisional::{VargaSchemes, varga_position};
+
+/// Varga groups with classical Vimshopaka weights
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VargaGroup {
+    Shadvarga,
+    Saptavarga,
+    Dashavarga,
+    Shodashavarga,
+}
+
+impl VargaGroup {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VargaGroup::Shadvarga => "Shadvarga",
+            VargaGroup::Saptavarga => "Saptavarga",
+            VargaGroup::Dashavarga => "Dashavarga",
+            VargaGroup::Shodashavarga => "Shodashavarga",
+        }
+    }
+
+    /// (division, weight); weights sum to 20
+    pub fn weights(&self) -> &'static [(u8, f64)] {
+        match self {
+            VargaGroup::Shadvarga => {
+                &[(1, 6.0), (2, 2.0), (3, 4.0), (9, 5.0), (12, 2.0), (30, 1.0)]
+            }
+            VargaGroup::Saptavarga => &[
+                (1, 5.0),
+                (2, 2.0),
+                (3, 3.0),
+                (7, 2.5),
+                (9, 4.5),
+                (12, 2.0),
+                (30, 1.0),
+            ],
+            VargaGroup::Dashavarga => &[
+                (1, 3.0),
+                (2, 1.5),
+                (3, 1.5),
+                (7, 1.5),
+                (9, 1.5),
+                (10, 1.5),
+                (12, 1.5),
+                (16, 1.5),
+                (30, 1.5),
+                (60, 5.0),
+            ],
+            VargaGroup::Shodashavarga => &[
+                (1, 3.5),
+                (2, 1.0),
+                (3, 1.0),
+                (4, 0.5),
+                (7, 0.5),
+                (9, 3.0),
+                (10, 0.5),
+                (12, 0.5),
+                (16, 2.0),
+                (20, 0.5),
+                (24, 0.5),
+                (27, 0.5),
+                (30, 1.0),
+                (40, 0.5),
+                (45, 0.5),
+                (60, 4.0),
+            ],
+        }
+    }
+
+    /// Amsa name for `count` good vargas (Parijata, Uttama, ...)
+    ///
+    /// Shadvarga has no classical names.
+    pub fn amsa_name(&self, count: u8) -> Option<&'static str> {
+        let names: &[&str] = match self {
+            VargaGroup::Shadvarga => &[],
+            VargaGroup::Saptavarga => &[
+                "Kimshuka", "Vyanjana", "Chamara", "Chatra", "Kundala", "Mukuta",
+            ],
+            VargaGroup::Dashavarga => &[
+                "Parijata",
+                "Uttama",
+                "Gopura",
+                "Simhasana",
+                "Paravata",
+                "Devaloka",
+                "Brahmaloka",
+                "Airavata",
+                "Sridhama",
+            ],
+            VargaGroup::Shodashavarga => &[
+                "Bhedaka",
+                "Kusuma",
+                "Nagapushpa",
+                "Kanduka",
+                "Kerala",
+                "Kalpavriksha",
+                "Chandanavana",
+                "Purnachandra",
+                "Uchchaihshrava",
+                "Dhanvantari",
+                "Suryakanta",
+                "Vidruma",
+                "Chakrasimhasana",
+                "Goloka",
+                "Srivallabha",
+            ],
+        };
+
+        names.get((count as usize).checked_sub(2)?).copied()
+    }
+}
+
+/// Dignity of a planet in one varga
+#[derive(Debug, Clone, Copy)]
+pub struct VargaDignity {
+    pub division: u8,
+    pub sign: u8,
+    pub dignity: Dignity,
+    /// 20 own/exalted/moolatrikona, else 18/15/10/7/5 by relationship
+    pub points: f64,
+}
+
+/// Vimshopaka bala of one planet for one varga group
+#[derive(Debug, Clone)]
+pub struct VimshopakaBala {
+    pub planet: DashaLord,
+    pub group: VargaGroup,
+    /// 0..20
+    pub points: f64,
+    pub vargas: Vec<VargaDignity>,
+    /// Vargas in own sign, moolatrikona or exaltation
+    pub good_vargas: u8,
+    pub amsa: Option<&'static str>,
+    /// Same sign in D1 and D9
+    pub vargottama: bool,
+}
+
+/// Vimshopaka bala for every visible planet in `planets`
+///
+/// Relationships are compound (natural + temporary), with the
+/// temporary part taken from the rashi (D1) chart.
+pub fn vimshopaka_bala(
+    planets: &[PlanetJS],
+    group: VargaGroup,
+    schemes: &VargaSchemes,
+) -> Vec<VimshopakaBala> {
+    let rashi: Vec<(DashaLord, u8)> = planets
+        .iter()
+        .filter_map(|p| {
+            let lord = DashaLord::from_name(&p.name)?;
+            Some((lord, (p.sidereal_deg / 30.0).floor() as u8 % 12))
+        })
+        .collect();
+
+    planets
+        .iter()
+        .filter_map(|p| {
+            let planet = DashaLord::from_name(&p.name)?;
+            let own_sign = rashi.iter().find(|(l, _)| *l == planet)?.1;
+
+            let relation_to = |lord: DashaLord| {
+                let temp = rashi
+                    .iter()
+                    .find(|(l, _)| *l == lord)
+                    .map(|(_, s)| temporary_friend(own_sign, *s))
+                    .unwrap_or(false);
+                compound_relation(natural_relation(planet, lord), temp)
+            };
+
+            let mut vargas = Vec::new();
+            let mut points = 0.0;
+
+            for &(division, weight) in group.weights() {
+                let (sign, _) = varga_position(division, p.sidereal_deg, schemes.get(division));
+                let d = dignity(planet, sign, relation_to)?;
+                let pts = dignity_points(d, relation_to(Sign::from_index(sign).lord()));
+
+                points += weight * pts / 20.0;
+                vargas.push(VargaDignity {
+                    division,
+                    sign,
+                    dignity: d,
+                    points: pts,
+                });
+            }
+
+            let good_vargas = vargas
+                .iter()
+                .filter(|v| {
+                    matches!(
+                        v.dignity,
+                        Dignity::Exalted | Dignity::Moolatrikona | Dignity::Own
+                    )
+                })
+                .count() as u8;
+
+            Some(VimshopakaBala {
+                planet,
+                group,
+                points,
+                vargas,
+                good_vargas,
+                amsa: group.amsa_name(good_vargas),
+                vargottama: is_vargottama(p.sidereal_deg, schemes),
+            })
+        })
+        .collect()
+}
+
+/// Same sign in the rashi and navamsa charts
+pub fn is_vargottama(lon_deg: f64, schemes: &VargaSchemes) -> bool {
+    varga_position(1, lon_deg, schemes.get(1)).0 == varga_position(9, lon_deg, schemes.get(9)).0
+}
+
+/// Vimshopaka points of a dignity
+///
+/// Debilitation is scored by the relationship with the sign lord.
+fn dignity_points(d: Dignity, relation_to_lord: Relation) -> f64 {
+    let by_relation = |r: Relation| match r {
+        Relation::GreatFriend => 18.0,
+        Relation::Friend => 15.0,
+        Relation::Neutral => 10.0,
+        Relation::Enemy => 7.0,
+        Relation::GreatEnemy => 5.0,
+    };
+
+    match d {
+        Dignity::Exalted | Dignity::Moolatrikona | Dignity::Own => 20.0,
+        Dignity::GreatFriend => 18.0,
+        Dignity::Friend => 15.0,
+        Dignity::Neutral => 10.0,
+        Dignity::Enemy => 7.0,
+        Dignity::GreatEnemy => 5.0,
+        Dignity::Debilitated => by_relation(relation_to_lord),
+    }
+}
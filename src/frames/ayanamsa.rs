@@ -12,17 +12,83 @@ use std::f64::consts::PI;
 
 /// Compute Lahiri ayanamsa (radians) for given Julian Day (TT)
 pub fn lahiri_ayanamsa(jd_tt: f64) -> f64 {
-    // Julian centuries from J2000
-    let t = (jd_tt - 2451545.0) / 36525.0;
-
     // Lahiri ayanamsa at J2000 (arcseconds)
     // 23° 51′ 00.00″ = 23.85°
     // = 85860 arcseconds
-    let ayan_0 = 85860.0;
+    precessed(85860.0, jd_tt)
+}
+
+/// Ayanamsa variants
+///
+/// Each is its value at J2000 carried by the same precession
+/// in longitude, so they differ by a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ayanamsa {
+    /// 23° 51′ 00″ at J2000 (this crate's original value)
+    #[default]
+    Lahiri,
+    /// 23° 51′ 25.53″ at J2000 (Indian Astronomical Ephemeris)
+    LahiriIae,
+    /// 22° 24′ 38.8″ at J2000
+    Raman,
+    /// 23° 45′ 36.9″ at J2000
+    Krishnamurti,
+    /// 24° 44′ 25.1″ at J2000
+    FaganBradley,
+    /// 22° 28′ 43.7″ at J2000
+    Yukteshwar,
+}
+
+impl Ayanamsa {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Ayanamsa::Lahiri => "Lahiri",
+            Ayanamsa::LahiriIae => "LahiriIAE",
+            Ayanamsa::Raman => "Raman",
+            Ayanamsa::Krishnamurti => "Krishnamurti",
+            Ayanamsa::FaganBradley => "FaganBradley",
+            Ayanamsa::Yukteshwar => "Yukteshwar",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Lahiri" => Some(Ayanamsa::Lahiri),
+            "LahiriIAE" => Some(Ayanamsa::LahiriIae),
+            "Raman" => Some(Ayanamsa::Raman),
+            "Krishnamurti" | "KP" => Some(Ayanamsa::Krishnamurti),
+            "FaganBradley" => Some(Ayanamsa::FaganBradley),
+            "Yukteshwar" => Some(Ayanamsa::Yukteshwar),
+            _ => None,
+        }
+    }
+
+    /// Value at J2000 (arcseconds)
+    fn j2000_arcsec(&self) -> f64 {
+        match self {
+            Ayanamsa::Lahiri => 85860.0,
+            Ayanamsa::LahiriIae => 85885.53,
+            Ayanamsa::Raman => 80678.8,
+            Ayanamsa::Krishnamurti => 85536.9,
+            Ayanamsa::FaganBradley => 89065.1,
+            Ayanamsa::Yukteshwar => 80923.7,
+        }
+    }
+
+    /// Ayanamsa (radians) for given Julian Day (TT)
+    pub fn value(&self, jd_tt: f64) -> f64 {
+        precessed(self.j2000_arcsec(), jd_tt)
+    }
+}
+
+/// J2000 value (arcseconds) carried to `jd_tt`, in radians
+fn precessed(j2000_arcsec: f64, jd_tt: f64) -> f64 {
+    // Julian centuries from J2000
+    let t = (jd_tt - 2451545.0) / 36525.0;
 
     // Annual precession in longitude (arcseconds)
     // IAU 1976 / Meeus
-    let ayan_arcsec = ayan_0 + 5029.0966 * t + 1.11113 * t * t - 0.000006 * t * t * t;
+    let ayan_arcsec = j2000_arcsec + 5029.0966 * t + 1.11113 * t * t - 0.000006 * t * t * t;
 
     arcsec_to_rad(ayan_arcsec)
 }
@@ -1,17 +1,23 @@
 pub mod astrology;
+pub mod compat;
 pub mod dasha;
 pub mod divisional;
 pub mod ephemeris;
 pub mod frames;
 pub mod math;
+pub mod panchang;
 pub mod time;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+use std::collections::HashMap;
 use std::f64::consts::TAU;
 
 // use crate::astrology::nakshatra_calc::compute_nakshatra;
-use crate::dasha::DashaMode;
+use crate::compat::{Compatibility, MoonLongitude, NutationConvention};
+use crate::dasha::seed::DashaSeed;
+use crate::dasha::year::{DashaClock, DashaYear, YearsMonthsDays};
+use crate::dasha::{DashaConfig, DashaSystem};
 // use crate::ephemeris::moon_mean::moon_mean_longitude_tropical;
 // ===================== TIME =====================
 use crate::time::calendar::jd_to_calendar;
@@ -26,25 +32,58 @@ use crate::ephemeris::planets::{
 };
 
 // ===================== FRAME =====================
-use crate::frames::ayanamsa::lahiri_ayanamsa;
+use crate::frames::ayanamsa::Ayanamsa;
+use crate::frames::nutation::nutation;
 
 // ===================== ASTROLOGY =====================
-use crate::astrology::houses::{ascendant_sidereal, whole_sign_houses};
+use crate::astrology::angles::angles_tropical;
+use crate::astrology::bhava::{bhava_chalit, sripati_bhavas};
+use crate::astrology::houses::{
+    HouseSystem, Sign, ascendant_tropical, house_cusps, whole_sign_houses,
+};
+use crate::astrology::kp::{KpPoint, kp_cusps, kp_planets};
 use crate::astrology::nakshatra::nakshatra_from_sidereal_lon;
+use crate::astrology::upagraha::aprakasha_grahas;
 
 // ===================== DASHAS =====================
-use crate::dasha::vimshottari::{
-    DashaLord, antardasha_timeline, mahadasha_timeline, pratyantardasha_timeline,
+use crate::dasha::ashtottari::{ASHTOTTARI, ashtottari_applicability};
+use crate::dasha::chara::{chara_antardashas, chara_dasha};
+use crate::dasha::conditional::ConditionalDasha;
+use crate::dasha::drig::{drig_antardashas, drig_dasha};
+use crate::dasha::kalachakra::{
+    kalachakra_antardasha_timeline, kalachakra_birth, kalachakra_mahadasha_timeline,
+};
+use crate::dasha::nakshatra_dasha::{
+    DashaNode, DashaStream, LEVEL_NAMES, MAX_DEPTH, NakshatraDasha, active_chain, birth_balance,
+    nakshatra_antardasha_timeline, nakshatra_mahadasha_timeline,
+    nakshatra_pratyantardasha_timeline, sub_periods,
+};
+use crate::dasha::narayana::{narayana_antardashas, narayana_dasha};
+use crate::dasha::rashi::{RashiAntarPeriod, RashiChart, RashiPeriod};
+use crate::dasha::sandhi::{LordPlacement, sandhi_windows};
+use crate::dasha::shoola::{
+    niryana_shoola_antardashas, niryana_shoola_dasha, shoola_antardashas, shoola_dasha,
+};
+use crate::dasha::sthira::{sthira_antardashas, sthira_dasha};
+use crate::dasha::vimshottari::{AntarPeriod, DashaLord, DashaPeriod, PratyPeriod, VIMSHOTTARI};
+use crate::dasha::yogini::{
+    Yogini, yogini_antardasha_timeline, yogini_mahadasha_timeline, yogini_pratyantardasha_timeline,
 };
+use crate::panchang::daily::{DailyPanchang, SignPeriod};
+use crate::panchang::limbs::LimbPeriod;
+use crate::panchang::{Panchang, panchang_at};
 
 // ===================== DIVISIONAL =====================
-use crate::divisional::divisional_chart;
+use crate::divisional::{
+    DivisionalPlanet, VargaGroup, VargaScheme, VargaSchemes, divisional_chart_with_lagna,
+    register_varga_table, vimshopaka_bala,
+};
 
 // =====================================================
 // =================== JS STRUCTS =======================
 // =====================================================
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 pub struct PlanetJS {
     pub name: String,
     pub tropical_deg: f64,
@@ -57,6 +96,44 @@ pub struct HouseJS {
     pub sign: String,
 }
 
+#[derive(Serialize)]
+pub struct AnglesJS {
+    pub ascendant_deg: f64,
+    pub descendant_deg: f64,
+    pub mc_deg: f64,
+    pub ic_deg: f64,
+    pub vertex_deg: f64,
+    pub anti_vertex_deg: f64,
+    pub east_point_deg: f64,
+}
+
+/// Sripati bhava (sidereal degrees)
+#[derive(Serialize)]
+pub struct BhavaJS {
+    pub number: u8,
+    pub madhya_deg: f64,
+    pub start_sandhi_deg: f64,
+    pub end_sandhi_deg: f64,
+}
+
+#[derive(Serialize)]
+pub struct BhavaPlacementJS {
+    pub planet: String,
+    pub rashi_house: u8,
+    pub bhava: u8,
+    pub shifted: bool,
+}
+
+#[derive(Serialize)]
+pub struct KpPointJS {
+    pub name: String,
+    pub longitude_deg: f64,
+    pub sign_lord: String,
+    pub star_lord: String,
+    pub sub_lord: String,
+    pub sub_sub_lord: String,
+}
+
 #[derive(Serialize)]
 pub struct DashaPeriodJS {
     pub maha: String,
@@ -68,17 +145,212 @@ pub struct DashaPeriodJS {
     pub end_date: String,
 }
 
+/// A dasha period at any level, by its chain of lords
+#[derive(Serialize)]
+pub struct DashaNodeJS {
+    pub level: String,
+    /// Mahadasha lord first
+    pub lords: Vec<String>,
+    pub start_jd: f64,
+    pub end_jd: f64,
+    pub start_date: String,
+    pub end_date: String,
+}
+
+/// Natal sign and whole-sign house of a dasha lord
+#[derive(Serialize)]
+pub struct LordPlacementJS {
+    pub lord: String,
+    pub sign: String,
+    pub house: u8,
+}
+
+/// Hand-over from one dasha period to the next
+#[derive(Serialize)]
+pub struct DashaTransitionJS {
+    pub jd_tt: f64,
+    pub date: String,
+    /// Highest level that changes
+    pub level: String,
+    /// Periods ending and starting here, at `level`
+    pub outgoing: DashaNodeJS,
+    pub incoming: DashaNodeJS,
+    /// Mahadasha lord first
+    pub outgoing_placements: Vec<LordPlacementJS>,
+    pub incoming_placements: Vec<LordPlacementJS>,
+    pub sandhi_start_jd: f64,
+    pub sandhi_end_jd: f64,
+    pub sandhi_start: String,
+    pub sandhi_end: String,
+}
+
+/// One panchang limb with its start and end
+#[derive(Serialize)]
+pub struct LimbJS {
+    pub name: String,
+    /// From 0 (tithi 0..29, karana 0..59, nakshatra and yoga
+    /// 0..26, vara 0 = Sunday)
+    pub index: usize,
+    pub start_jd: f64,
+    pub end_jd: f64,
+    /// Local civil time, minute precision
+    pub start: String,
+    pub end: String,
+}
+
+#[derive(Serialize)]
+pub struct PanchangJS {
+    pub tithi: LimbJS,
+    /// Missing where the Sun does not rise
+    pub vara: Option<LimbJS>,
+    pub nakshatra: LimbJS,
+    pub yoga: LimbJS,
+    pub karana: LimbJS,
+}
+
+/// A moment as JD (TT) and local civil time
+#[derive(Serialize)]
+pub struct TimeJS {
+    pub jd_tt: f64,
+    /// Local civil time, minute precision
+    pub time: String,
+}
+
+#[derive(Serialize)]
+pub struct SignPeriodJS {
+    pub sign: String,
+    pub start_jd: f64,
+    pub end_jd: f64,
+    pub start: String,
+    pub end: String,
+}
+
+/// Panchang of one civil day
+#[derive(Serialize)]
+pub struct DailyPanchangJS {
+    pub date: String,
+
+    pub sunrise: Option<TimeJS>,
+    pub sunset: Option<TimeJS>,
+    pub moonrise: Option<TimeJS>,
+    pub moonset: Option<TimeJS>,
+
+    /// Limbs from sunrise to the next sunrise, with their changes
+    pub vara: Option<LimbJS>,
+    pub tithis: Vec<LimbJS>,
+    pub nakshatras: Vec<LimbJS>,
+    pub yogas: Vec<LimbJS>,
+    pub karanas: Vec<LimbJS>,
+
+    /// Sidereal signs over the same span
+    pub sun_signs: Vec<SignPeriodJS>,
+    pub moon_signs: Vec<SignPeriodJS>,
+
+    pub paksha: String,
+    pub ayana: String,
+    pub ritu: String,
+}
+
+/// Compatibility settings a kundli was computed with
+#[derive(Serialize, Deserialize)]
+pub struct CompatibilityJS {
+    pub ayanamsa: String,
+    pub ayanamsa_deg: f64,
+    pub moon: String,
+    pub nutation: String,
+}
+
+/// A dasha span in years, months and days
+#[derive(Serialize)]
+pub struct YmdJS {
+    pub years: u32,
+    pub months: u32,
+    pub days: f64,
+}
+
+/// Unelapsed part of the mahadasha running at birth
+#[derive(Serialize)]
+pub struct DashaBalanceJS {
+    pub lord: String,
+    /// Dasha years left at birth
+    pub total_years: f64,
+    pub balance: YmdJS,
+}
+
+/// A period running at the query date
+#[derive(Serialize)]
+pub struct ActiveDashaJS {
+    pub period: DashaNodeJS,
+    /// Dasha years left in the period
+    pub remaining_years: f64,
+    pub remaining_days: f64,
+    pub remaining: YmdJS,
+}
+
+/// Result of `dasha_at`
+#[derive(Serialize)]
+pub struct DashaQueryJS {
+    pub jd_tt: f64,
+    pub date: String,
+    /// Mahadasha first
+    pub active: Vec<ActiveDashaJS>,
+    pub birth_balance: DashaBalanceJS,
+}
+
+/// Kalachakra birth pada with its deha and jeeva signs
+#[derive(Serialize)]
+pub struct KalachakraJS {
+    pub chakra: String,
+    pub nakshatra: String,
+    pub pada: u8,
+    pub deha: String,
+    pub jeeva: String,
+    pub paramayus: f64,
+}
+
 #[derive(Serialize)]
 pub struct DivisionalPlanetJS {
     pub planet: String,
     pub sign: String,
     pub degree: f64,
+    pub amsha_index: u16,
+    pub amsha_name: Option<String>,
+    pub amsha_nature: Option<String>,
 }
 
 #[derive(Serialize)]
 pub struct DivisionalChartJS {
     pub division: u8,
+    pub ascendant: Option<DivisionalPlanetJS>,
+    pub houses: Vec<HouseJS>,
     pub planets: Vec<DivisionalPlanetJS>,
+    pub special_points: Vec<DivisionalPlanetJS>,
+}
+
+#[derive(Serialize)]
+pub struct VargaDignityJS {
+    pub division: u8,
+    pub sign: String,
+    pub dignity: String,
+    pub points: f64,
+}
+
+#[derive(Serialize)]
+pub struct VimshopakaJS {
+    pub planet: String,
+    pub vargottama: bool,
+
+    pub shadvarga: f64,
+    pub saptavarga: f64,
+    pub dashavarga: f64,
+    pub shodashavarga: f64,
+
+    pub saptavarga_amsa: Option<String>,
+    pub dashavarga_amsa: Option<String>,
+    pub shodashavarga_amsa: Option<String>,
+
+    /// Dignity in each of the 16 vargas
+    pub vargas: Vec<VargaDignityJS>,
 }
 
 #[derive(Serialize)]
@@ -87,7 +359,9 @@ pub struct KundliJS {
     pub jd_ut: f64,
 
     pub ascendant_sidereal_deg: f64,
+    pub angles: AnglesJS,
 
+    /// Moon used for the nakshatra and dashas
     pub moon_sidereal_deg: f64,
     pub nakshatra: String,
     pub pada: u8,
@@ -96,11 +370,240 @@ pub struct KundliJS {
     pub planets: Vec<PlanetJS>,
     pub houses: Vec<HouseJS>,
 
+    /// Sripati Bhava Chalit
+    pub bhavas: Vec<BhavaJS>,
+    pub bhava_chalit: Vec<BhavaPlacementJS>,
+
+    /// Empty unless the `kp` section is asked for
+    pub kp_planets: Vec<KpPointJS>,
+    /// Placidus cusps; empty where Placidus is undefined or
+    /// without the `kp` section
+    pub kp_cusps: Vec<KpPointJS>,
+
+    /// System used for the dasha lists below
+    pub dasha_system: String,
+    /// Dasha year length used for the lists
+    pub dasha_year: String,
+    /// Starting point of the nakshatra dashas
+    pub dasha_seed: String,
+    /// Longitude the nakshatra dashas start from (sidereal degrees)
+    pub dasha_seed_deg: f64,
+    /// Conditional dasha systems whose classical conditions hold
+    pub applicable_dashas: Vec<String>,
     pub mahadashas: Vec<DashaPeriodJS>,
     pub antardashas: Vec<DashaPeriodJS>,
     pub pratyantardashas: Vec<DashaPeriodJS>,
+    /// Balance of the first mahadasha at birth
+    pub dasha_balance: Option<DashaBalanceJS>,
+    /// Only with the `kalachakra` section
+    pub kalachakra: Option<KalachakraJS>,
 
+    /// Empty unless the `vargas` section is asked for
     pub divisional_charts: Vec<DivisionalChartJS>,
+    /// Empty unless the `vimshopaka` section is asked for
+    pub vimshopaka: Vec<VimshopakaJS>,
+
+    pub compatibility: CompatibilityJS,
+
+    /// Panchang at birth, only with the `panchang` section
+    pub panchang: Option<PanchangJS>,
+}
+
+// =====================================================
+// =================== OPTIONS ==========================
+// =====================================================
+
+/// Optional settings for `generate_kundli_with_options`
+///
+/// Every field may be omitted from the JS object.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct KundliOptions {
+    /// Varga scheme name per division, e.g. `{ "3": "Jagannatha" }`.
+    /// Names: Parashari, Parivritti, Jagannatha, Somanatha,
+    /// Kashinatha, or a table registered with `register_varga_table`.
+    pub varga_schemes: HashMap<String, String>,
+    /// Dasha system: Vimshottari (default), Yogini, Ashtottari,
+    /// Shodashottari, Dwadashottari, Panchottari, Shatabdika,
+    /// Chaturashiti-sama, Dwisaptati-sama, Shashtihayani,
+    /// CharaKNRao, CharaRath, Narayana, NarayanaD9, NarayanaD10,
+    /// Sthira, Shoola, NiryanaShoola, Drig or Kalachakra.
+    pub dasha_system: Option<String>,
+    /// Dasha year: Savana, Julian, Tropical, Gregorian, Sidereal
+    /// or SolarReturn (default: from the compatibility preset).
+    pub dasha_year: Option<String>,
+    /// Nakshatra dasha start: Moon (default), Lagna, Stronger,
+    /// Kshema, Utpanna or Adhana.
+    pub dasha_seed: Option<String>,
+    /// Compatibility preset: Astronomical (default), JHora or KP.
+    /// The fields below override single settings of the preset.
+    pub compatibility: Option<String>,
+    /// Lahiri, LahiriIAE, Raman, Krishnamurti, FaganBradley or
+    /// Yukteshwar.
+    pub ayanamsa: Option<String>,
+    /// Moon for the nakshatra and dashas: True or Mean.
+    pub moon: Option<String>,
+    /// Nutation: Mean or Apparent.
+    pub nutation: Option<String>,
+    /// Costlier parts of the kundli to compute (none by default).
+    pub sections: KundliSections,
+}
+
+/// Optional parts of a kundli, each left out unless asked for
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(default)]
+pub struct KundliSections {
+    /// D1–D30 and D40–D150 charts
+    pub vargas: bool,
+    /// Vimshopaka bala in the four varga groups
+    pub vimshopaka: bool,
+    /// KP lords of the planets and Placidus cusps
+    pub kp: bool,
+    /// Kalachakra birth pada
+    pub kalachakra: bool,
+    /// Panchang at birth
+    pub panchang: bool,
+}
+
+impl KundliSections {
+    pub const ALL: KundliSections = KundliSections {
+        vargas: true,
+        vimshopaka: true,
+        kp: true,
+        kalachakra: true,
+        panchang: true,
+    };
+}
+
+impl KundliOptions {
+    fn varga_schemes(&self) -> Result<VargaSchemes, String> {
+        let mut out = VargaSchemes::default();
+
+        for (division, name) in &self.varga_schemes {
+            let division: u8 = division
+                .trim_start_matches(['D', 'd'])
+                .parse()
+                .map_err(|_| format!("invalid division: {division}"))?;
+            let scheme = VargaScheme::from_name(name)
+                .ok_or_else(|| format!("unknown varga scheme: {name}"))?;
+
+            out.set(division, scheme);
+        }
+
+        Ok(out)
+    }
+
+    fn compatibility(&self) -> Result<Compatibility, String> {
+        let mut out = match &self.compatibility {
+            None => Compatibility::default(),
+            Some(name) => Compatibility::preset(name)
+                .ok_or_else(|| format!("unknown compatibility preset: {name}"))?,
+        };
+
+        if let Some(name) = &self.ayanamsa {
+            out.ayanamsa =
+                Ayanamsa::from_name(name).ok_or_else(|| format!("unknown ayanamsa: {name}"))?;
+        }
+        if let Some(name) = &self.moon {
+            out.moon =
+                MoonLongitude::from_name(name).ok_or_else(|| format!("unknown moon: {name}"))?;
+        }
+        if let Some(name) = &self.nutation {
+            out.nutation = NutationConvention::from_name(name)
+                .ok_or_else(|| format!("unknown nutation convention: {name}"))?;
+        }
+        if self.dasha_year.is_some() {
+            out.year = dasha_year(self.dasha_year.as_deref())?;
+        }
+
+        Ok(out)
+    }
+
+    fn dasha_config(&self, compat: &Compatibility) -> Result<DashaConfig, String> {
+        let system = match &self.dasha_system {
+            None => DashaSystem::default(),
+            Some(name) => DashaSystem::from_name(name)
+                .ok_or_else(|| format!("unknown dasha system: {name}"))?,
+        };
+        let year = compat.year;
+        let seed = match &self.dasha_seed {
+            None => DashaSeed::default(),
+            Some(name) => {
+                DashaSeed::from_name(name).ok_or_else(|| format!("unknown dasha seed: {name}"))?
+            }
+        };
+
+        Ok(DashaConfig { system, year, seed })
+    }
+}
+
+/// Fields of a generated kundli needed to query its dashas
+#[derive(Deserialize)]
+struct KundliDashaInput {
+    jd_tt: f64,
+    ascendant_sidereal_deg: f64,
+    planets: Vec<PlanetJS>,
+    dasha_seed_deg: f64,
+    dasha_system: String,
+    dasha_year: String,
+    compatibility: CompatibilityJS,
+}
+
+impl KundliDashaInput {
+    /// Nakshatra dasha table and clock of the kundli
+    fn nakshatra_dasha(&self) -> Result<(&'static NakshatraDasha, DashaClock), JsValue> {
+        let system = DashaSystem::from_name(&self.dasha_system)
+            .and_then(|s| s.nakshatra_system())
+            .ok_or_else(|| {
+                JsValue::from_str(&format!(
+                    "not a nakshatra dasha system: {}",
+                    self.dasha_system
+                ))
+            })?;
+        let year = dasha_year(Some(&self.dasha_year)).map_err(|e| JsValue::from_str(&e))?;
+        let compat = self.compatibility().map_err(|e| JsValue::from_str(&e))?;
+
+        Ok((system, DashaClock::with_compat(self.jd_tt, year, &compat)))
+    }
+
+    /// Settings the kundli was computed with
+    fn compatibility(&self) -> Result<Compatibility, String> {
+        let c = &self.compatibility;
+
+        Ok(Compatibility {
+            ayanamsa: Ayanamsa::from_name(&c.ayanamsa)
+                .ok_or_else(|| format!("unknown ayanamsa: {}", c.ayanamsa))?,
+            moon: MoonLongitude::from_name(&c.moon)
+                .ok_or_else(|| format!("unknown moon: {}", c.moon))?,
+            nutation: NutationConvention::from_name(&c.nutation)
+                .ok_or_else(|| format!("unknown nutation convention: {}", c.nutation))?,
+            year: dasha_year(Some(&self.dasha_year))?,
+        })
+    }
+}
+
+/// Most periods `dasha_window` returns
+const MAX_WINDOW_PERIODS: usize = 10_000;
+
+/// Most days `daily_panchang` computes (a leap year)
+const MAX_PANCHANG_DAYS: u32 = 366;
+
+/// Dasha level requested from JS: 1 (mahadasha) ..= MAX_DEPTH
+fn dasha_depth(depth: usize) -> Result<usize, String> {
+    if (1..=MAX_DEPTH).contains(&depth) {
+        Ok(depth)
+    } else {
+        Err(format!("dasha depth must be 1..={MAX_DEPTH}, got {depth}"))
+    }
+}
+
+fn dasha_year(name: Option<&str>) -> Result<DashaYear, String> {
+    match name {
+        None => Ok(DashaYear::default()),
+        Some(name) => {
+            DashaYear::from_name(name).ok_or_else(|| format!("unknown dasha year: {name}"))
+        }
+    }
 }
 
 // =====================================================
@@ -119,15 +622,311 @@ fn lord_name(l: DashaLord) -> String {
     format!("{:?}", l)
 }
 
+fn kp_point_js(p: KpPoint) -> KpPointJS {
+    KpPointJS {
+        name: p.name,
+        longitude_deg: p.longitude_deg,
+        sign_lord: lord_name(p.lords.sign_lord),
+        star_lord: lord_name(p.lords.star_lord),
+        sub_lord: lord_name(p.lords.sub_lord),
+        sub_sub_lord: lord_name(p.lords.sub_sub_lord),
+    }
+}
+
+fn divisional_planet_js(p: DivisionalPlanet) -> DivisionalPlanetJS {
+    DivisionalPlanetJS {
+        planet: p.planet,
+        sign: p.sign,
+        degree: p.degree,
+        amsha_index: p.amsha.index,
+        amsha_name: p.amsha.name.map(str::to_string),
+        amsha_nature: p.amsha.nature.map(|n| n.name().to_string()),
+    }
+}
+
 fn fmt_date(jd: f64) -> String {
     let d = jd_to_calendar(jd);
     format!("{:04}-{:02}-{:02}", d.year, d.month, d.day)
 }
 
+type DashaLists = (Vec<DashaPeriodJS>, Vec<DashaPeriodJS>, Vec<DashaPeriodJS>);
+
+fn dasha_period_js(
+    maha: String,
+    antara: Option<String>,
+    praty: Option<String>,
+    start_jd: f64,
+    end_jd: f64,
+) -> DashaPeriodJS {
+    DashaPeriodJS {
+        maha,
+        antara,
+        praty,
+        start_jd,
+        end_jd,
+        start_date: fmt_date(start_jd),
+        end_date: fmt_date(end_jd),
+    }
+}
+
+fn nakshatra_dashas(system: &NakshatraDasha, clock: &DashaClock, seed_sid_deg: f64) -> DashaLists {
+    lord_dashas(
+        nakshatra_mahadasha_timeline(system, clock, seed_sid_deg),
+        nakshatra_antardasha_timeline(system, clock, seed_sid_deg),
+        nakshatra_pratyantardasha_timeline(system, clock, seed_sid_deg),
+    )
+}
+
+/// JS lists for a planet-lord dasha
+fn lord_dashas(
+    maha: Vec<DashaPeriod>,
+    antara: Vec<AntarPeriod>,
+    praty: Vec<PratyPeriod>,
+) -> DashaLists {
+    let maha = maha
+        .into_iter()
+        .map(|d| dasha_period_js(lord_name(d.lord), None, None, d.start_jd, d.end_jd))
+        .collect();
+
+    let antara = antara
+        .into_iter()
+        .map(|d| {
+            dasha_period_js(
+                lord_name(d.maha),
+                Some(lord_name(d.antara)),
+                None,
+                d.start_jd,
+                d.end_jd,
+            )
+        })
+        .collect();
+
+    let praty = praty
+        .into_iter()
+        .map(|d| {
+            dasha_period_js(
+                lord_name(d.maha),
+                Some(lord_name(d.antara)),
+                Some(lord_name(d.praty)),
+                d.start_jd,
+                d.end_jd,
+            )
+        })
+        .collect();
+
+    (maha, antara, praty)
+}
+
+/// JS lists for a rashi dasha (no pratyantar level)
+fn sign_dashas(maha: Vec<RashiPeriod>, antara: Vec<RashiAntarPeriod>) -> DashaLists {
+    let sign_name = |s: u8| Sign::from_index(s).name().to_string();
+
+    let maha = maha
+        .into_iter()
+        .map(|d| dasha_period_js(sign_name(d.sign), None, None, d.start_jd, d.end_jd))
+        .collect();
+
+    let antara = antara
+        .into_iter()
+        .map(|d| {
+            dasha_period_js(
+                sign_name(d.maha),
+                Some(sign_name(d.antara)),
+                None,
+                d.start_jd,
+                d.end_jd,
+            )
+        })
+        .collect();
+
+    (maha, antara, Vec::new())
+}
+
+fn ymd_js(years: f64, year: DashaYear) -> YmdJS {
+    let ymd = YearsMonthsDays::from_years(years, year);
+    YmdJS {
+        years: ymd.years,
+        months: ymd.months,
+        days: ymd.days,
+    }
+}
+
+fn dasha_balance_js(lord: String, years: f64, year: DashaYear) -> DashaBalanceJS {
+    DashaBalanceJS {
+        lord,
+        total_years: years,
+        balance: ymd_js(years, year),
+    }
+}
+
+fn level_name(level: usize) -> String {
+    LEVEL_NAMES
+        .get(level - 1)
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| format!("Level {level}"))
+}
+
+/// Local civil date and time (minute precision) of a JD (TT)
+fn fmt_local(jd_tt: f64, tz_offset_hours: f64) -> String {
+    let local = jd_ut_from_tt(jd_tt) + tz_offset_hours / 24.0;
+
+    let minutes = ((local + 0.5) * 1440.0).round();
+    let day = (minutes / 1440.0).floor();
+    let minute = (minutes - day * 1440.0) as u32;
+
+    format!(
+        "{} {:02}:{:02}",
+        fmt_date(day - 0.5),
+        minute / 60,
+        minute % 60
+    )
+}
+
+fn limb_js(l: &LimbPeriod, tz_offset_hours: f64) -> LimbJS {
+    LimbJS {
+        name: l.name.to_string(),
+        index: l.index,
+        start_jd: l.start_jd,
+        end_jd: l.end_jd,
+        start: fmt_local(l.start_jd, tz_offset_hours),
+        end: fmt_local(l.end_jd, tz_offset_hours),
+    }
+}
+
+fn panchang_js(p: &Panchang, tz_offset_hours: f64) -> PanchangJS {
+    PanchangJS {
+        tithi: limb_js(&p.tithi, tz_offset_hours),
+        vara: p.vara.as_ref().map(|v| limb_js(v, tz_offset_hours)),
+        nakshatra: limb_js(&p.nakshatra, tz_offset_hours),
+        yoga: limb_js(&p.yoga, tz_offset_hours),
+        karana: limb_js(&p.karana, tz_offset_hours),
+    }
+}
+
+fn daily_panchang_js(d: &DailyPanchang, tz_offset_hours: f64) -> DailyPanchangJS {
+    let time = |jd: Option<f64>| {
+        jd.map(|jd_tt| TimeJS {
+            jd_tt,
+            time: fmt_local(jd_tt, tz_offset_hours),
+        })
+    };
+    let limbs = |list: &[LimbPeriod]| {
+        list.iter()
+            .map(|l| limb_js(l, tz_offset_hours))
+            .collect::<Vec<_>>()
+    };
+    let signs = |list: &[SignPeriod]| {
+        list.iter()
+            .map(|p| SignPeriodJS {
+                sign: Sign::from_index(p.sign).name().to_string(),
+                start_jd: p.start_jd,
+                end_jd: p.end_jd,
+                start: fmt_local(p.start_jd, tz_offset_hours),
+                end: fmt_local(p.end_jd, tz_offset_hours),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    DailyPanchangJS {
+        date: format!("{:04}-{:02}-{:02}", d.year, d.month, d.day),
+
+        sunrise: time(d.sunrise),
+        sunset: time(d.sunset),
+        moonrise: time(d.moonrise),
+        moonset: time(d.moonset),
+
+        vara: d.vara.as_ref().map(|v| limb_js(v, tz_offset_hours)),
+        tithis: limbs(&d.tithis),
+        nakshatras: limbs(&d.nakshatras),
+        yogas: limbs(&d.yogas),
+        karanas: limbs(&d.karanas),
+
+        sun_signs: signs(&d.sun_signs),
+        moon_signs: signs(&d.moon_signs),
+
+        paksha: d.paksha.name().to_string(),
+        ayana: d.ayana.name().to_string(),
+        ritu: d.ritu.name().to_string(),
+    }
+}
+
+fn dasha_node_js(n: &DashaNode) -> DashaNodeJS {
+    DashaNodeJS {
+        level: level_name(n.level()),
+        lords: n.lords.iter().map(|&l| lord_name(l)).collect(),
+        start_jd: n.start_jd,
+        end_jd: n.end_jd,
+        start_date: fmt_date(n.start_jd),
+        end_date: fmt_date(n.end_jd),
+    }
+}
+
+fn kalachakra_dashas(clock: &DashaClock, moon_sid_deg: f64) -> DashaLists {
+    let sign_name = |s: u8| Sign::from_index(s).name().to_string();
+
+    let maha = kalachakra_mahadasha_timeline(clock, moon_sid_deg)
+        .into_iter()
+        .map(|d| dasha_period_js(sign_name(d.sign), None, None, d.start_jd, d.end_jd))
+        .collect();
+
+    let antara = kalachakra_antardasha_timeline(clock, moon_sid_deg)
+        .into_iter()
+        .map(|d| {
+            dasha_period_js(
+                sign_name(d.maha),
+                Some(sign_name(d.antara)),
+                None,
+                d.start_jd,
+                d.end_jd,
+            )
+        })
+        .collect();
+
+    (maha, antara, Vec::new())
+}
+
+fn yogini_dashas(clock: &DashaClock, seed_sid_deg: f64) -> DashaLists {
+    let name = |y: Yogini| y.name().to_string();
+
+    let maha = yogini_mahadasha_timeline(clock, seed_sid_deg)
+        .into_iter()
+        .map(|d| dasha_period_js(name(d.yogini), None, None, d.start_jd, d.end_jd))
+        .collect();
+
+    let antara = yogini_antardasha_timeline(clock, seed_sid_deg)
+        .into_iter()
+        .map(|d| {
+            dasha_period_js(
+                name(d.maha),
+                Some(name(d.antara)),
+                None,
+                d.start_jd,
+                d.end_jd,
+            )
+        })
+        .collect();
+
+    let praty = yogini_pratyantardasha_timeline(clock, seed_sid_deg)
+        .into_iter()
+        .map(|d| {
+            dasha_period_js(
+                name(d.maha),
+                Some(name(d.antara)),
+                Some(name(d.praty)),
+                d.start_jd,
+                d.end_jd,
+            )
+        })
+        .collect();
+
+    (maha, antara, praty)
+}
+
 // =====================================================
 // =================== WASM API =========================
 // =====================================================
 
+/// Kundli with default settings and every section
 #[wasm_bindgen]
 pub fn generate_kundli(
     year: i32,
@@ -140,8 +939,171 @@ pub fn generate_kundli(
     latitude_deg: f64,
     longitude_deg: f64,
 ) -> JsValue {
-    // ---------- TIME ----------
-    let jd_tt = jd_tt_from_datetime(DateTimeInput {
+    let input = DateTimeInput {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        tz_offset_hours,
+    };
+
+    let result = build_kundli(
+        input,
+        latitude_deg,
+        longitude_deg,
+        &VargaSchemes::default(),
+        DashaConfig::default(),
+        &Compatibility::default(),
+        KundliSections::ALL,
+    );
+
+    serde_wasm_bindgen::to_value(&result).unwrap()
+}
+
+/// `generate_kundli` with a `KundliOptions` object
+///
+/// Only the sections named in `options.sections` are computed;
+/// the others are left empty.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn generate_kundli_with_options(
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: f64,
+    tz_offset_hours: f64,
+    latitude_deg: f64,
+    longitude_deg: f64,
+    options: JsValue,
+) -> Result<JsValue, JsValue> {
+    let options: KundliOptions = if options.is_undefined() || options.is_null() {
+        KundliOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)?
+    };
+
+    let varga_schemes = options.varga_schemes().map_err(|e| JsValue::from_str(&e))?;
+    let compat = options.compatibility().map_err(|e| JsValue::from_str(&e))?;
+    let dasha = options
+        .dasha_config(&compat)
+        .map_err(|e| JsValue::from_str(&e))?;
+
+    let input = DateTimeInput {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        tz_offset_hours,
+    };
+
+    let result = build_kundli(
+        input,
+        latitude_deg,
+        longitude_deg,
+        &varga_schemes,
+        dasha,
+        &compat,
+        options.sections,
+    );
+
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+/// Sub-periods of one dasha period, computed on demand
+///
+/// `lords` is the chain of the period (e.g. `["Saturn", "Mercury",
+/// "Ketu"]` for a pratyantardasha); the result is the next level
+/// down (sookshma here), at most deha (6 levels). Works for the
+/// planet-lord nakshatra dashas (Vimshottari, Ashtottari and the
+/// conditional ones); a lord without years in the system (Ketu
+/// in Ashtottari) is an error.
+/// `birth_jd` (TT), `dasha_year` and `options` (a `KundliOptions`
+/// object or undefined; only the compatibility settings are
+/// used) are only needed for solar return years.
+#[wasm_bindgen]
+pub fn dasha_sub_periods(
+    system: &str,
+    lords: Vec<String>,
+    start_jd: f64,
+    end_jd: f64,
+    birth_jd: f64,
+    dasha_year: Option<String>,
+    options: JsValue,
+) -> Result<JsValue, JsValue> {
+    let table = DashaSystem::from_name(system)
+        .and_then(|s| s.nakshatra_system())
+        .ok_or_else(|| JsValue::from_str(&format!("not a nakshatra dasha system: {system}")))?;
+
+    let lords = lords
+        .iter()
+        .map(|l| {
+            DashaLord::from_name(l).ok_or_else(|| JsValue::from_str(&format!("unknown lord: {l}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if lords.is_empty() {
+        return Err(JsValue::from_str("empty lord chain"));
+    }
+    dasha_depth(lords.len() + 1).map_err(|e| JsValue::from_str(&e))?;
+    table
+        .check_chain(&lords)
+        .map_err(|lord| JsValue::from_str(&format!("{lord:?} has no dasha in {}", table.name)))?;
+
+    let options: KundliOptions = if options.is_undefined() || options.is_null() {
+        KundliOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)?
+    };
+    let compat = options.compatibility().map_err(|e| JsValue::from_str(&e))?;
+
+    let year = self::dasha_year(dasha_year.as_deref()).map_err(|e| JsValue::from_str(&e))?;
+    let clock = DashaClock::with_compat(birth_jd, year, &compat);
+
+    let node = DashaNode {
+        lords,
+        start_jd,
+        end_jd,
+    };
+
+    let subs: Vec<DashaNodeJS> = sub_periods(table, &clock, &node)
+        .iter()
+        .map(dasha_node_js)
+        .collect();
+
+    Ok(serde_wasm_bindgen::to_value(&subs)?)
+}
+
+/// Dasha periods running at a date, with the birth balance
+///
+/// `kundli` is a result of `generate_kundli_with_options`; its
+/// dasha system, year and seed are used. `depth` is the number of
+/// levels returned (5 = down to prana, 6 at most). Works for the
+/// planet-lord nakshatra dashas.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn dasha_at(
+    kundli: JsValue,
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: f64,
+    tz_offset_hours: f64,
+    depth: u8,
+) -> Result<JsValue, JsValue> {
+    let k: KundliDashaInput = serde_wasm_bindgen::from_value(kundli)?;
+    let (system, clock) = k.nakshatra_dasha()?;
+    let dasha_year = clock.year;
+    let depth = dasha_depth(depth as usize).map_err(|e| JsValue::from_str(&e))?;
+
+    let jd = jd_tt_from_datetime(DateTimeInput {
         year,
         month,
         day,
@@ -151,14 +1113,256 @@ pub fn generate_kundli(
         tz_offset_hours,
     });
 
+    let active = active_chain(system, &clock, k.dasha_seed_deg, jd, depth)
+        .iter()
+        .map(|n| {
+            let remaining_years = n.remaining_years(&clock, jd);
+            ActiveDashaJS {
+                period: dasha_node_js(n),
+                remaining_years,
+                remaining_days: n.end_jd - jd,
+                remaining: ymd_js(remaining_years, dasha_year),
+            }
+        })
+        .collect();
+
+    let (lord, years) = birth_balance(system, k.dasha_seed_deg);
+
+    let result = DashaQueryJS {
+        jd_tt: jd,
+        date: fmt_date(jd),
+        active,
+        birth_balance: dasha_balance_js(lord_name(lord), years, dasha_year),
+    };
+
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+/// Periods of one level between two dates, in order
+///
+/// `kundli` is a result of `generate_kundli_with_options`. The
+/// range may lie anywhere: the mahadashas repeat before birth and
+/// after the first cycle. `depth` is the level returned
+/// (1 = mahadasha .. 6 = deha); at most 10 000 periods are
+/// returned, more is an error.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn dasha_window(
+    kundli: JsValue,
+    from_year: i32,
+    from_month: u8,
+    from_day: u8,
+    to_year: i32,
+    to_month: u8,
+    to_day: u8,
+    tz_offset_hours: f64,
+    depth: u8,
+) -> Result<JsValue, JsValue> {
+    let k: KundliDashaInput = serde_wasm_bindgen::from_value(kundli)?;
+    let (system, clock) = k.nakshatra_dasha()?;
+    let depth = dasha_depth(depth as usize).map_err(|e| JsValue::from_str(&e))?;
+
+    let jd = |year, month, day| {
+        jd_tt_from_datetime(DateTimeInput {
+            year,
+            month,
+            day,
+            hour: 0,
+            minute: 0,
+            second: 0.0,
+            tz_offset_hours,
+        })
+    };
+
+    let to_jd = jd(to_year, to_month, to_day);
+    let nodes: Vec<DashaNode> = DashaStream::at(
+        system,
+        clock,
+        k.dasha_seed_deg,
+        jd(from_year, from_month, from_day),
+        depth,
+    )
+    .take_while(|n| n.start_jd < to_jd)
+    .take(MAX_WINDOW_PERIODS + 1)
+    .collect();
+
+    if nodes.len() > MAX_WINDOW_PERIODS {
+        return Err(JsValue::from_str(&format!(
+            "more than {MAX_WINDOW_PERIODS} periods; shorten the range or use a lower depth"
+        )));
+    }
+
+    let periods: Vec<DashaNodeJS> = nodes.iter().map(dasha_node_js).collect();
+
+    Ok(serde_wasm_bindgen::to_value(&periods)?)
+}
+
+/// Dasha transitions between two dates, with their sandhi
+///
+/// `kundli` is a result of `generate_kundli_with_options`. Every
+/// boundary of the periods at `depth` (1 = mahadasha) is an event
+/// carrying the outgoing and incoming periods at the level that
+/// changes, and the natal sign and whole-sign house of every lord
+/// in both chains. The sandhi spans `sandhi_fraction` (above 0,
+/// at most 0.5) of the outgoing and incoming periods.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn dasha_transitions(
+    kundli: JsValue,
+    from_year: i32,
+    from_month: u8,
+    from_day: u8,
+    to_year: i32,
+    to_month: u8,
+    to_day: u8,
+    tz_offset_hours: f64,
+    depth: u8,
+    sandhi_fraction: f64,
+) -> Result<JsValue, JsValue> {
+    let k: KundliDashaInput = serde_wasm_bindgen::from_value(kundli)?;
+    let (system, clock) = k.nakshatra_dasha()?;
+    let depth = dasha_depth(depth as usize).map_err(|e| JsValue::from_str(&e))?;
+    if !(sandhi_fraction > 0.0 && sandhi_fraction <= 0.5) {
+        return Err(JsValue::from_str(&format!(
+            "sandhi fraction must be above 0 and at most 0.5, got {sandhi_fraction}"
+        )));
+    }
+    let chart = RashiChart::new(k.ascendant_sidereal_deg, &k.planets);
+
+    let jd = |year, month, day| {
+        jd_tt_from_datetime(DateTimeInput {
+            year,
+            month,
+            day,
+            hour: 0,
+            minute: 0,
+            second: 0.0,
+            tz_offset_hours,
+        })
+    };
+
+    let placements = |list: &[LordPlacement]| -> Vec<LordPlacementJS> {
+        list.iter()
+            .map(|p| LordPlacementJS {
+                lord: lord_name(p.lord),
+                sign: Sign::from_index(p.sign).name().to_string(),
+                house: p.house,
+            })
+            .collect()
+    };
+
+    let events: Vec<DashaTransitionJS> = sandhi_windows(
+        system,
+        &clock,
+        k.dasha_seed_deg,
+        &chart,
+        jd(from_year, from_month, from_day),
+        jd(to_year, to_month, to_day),
+        depth,
+        sandhi_fraction,
+    )
+    .iter()
+    .map(|w| {
+        let t = &w.transition;
+        DashaTransitionJS {
+            jd_tt: t.jd,
+            date: fmt_date(t.jd),
+            level: level_name(t.level),
+            outgoing: dasha_node_js(t.outgoing_period()),
+            incoming: dasha_node_js(t.incoming_period()),
+            outgoing_placements: placements(&t.outgoing_placements),
+            incoming_placements: placements(&t.incoming_placements),
+            sandhi_start_jd: w.start_jd,
+            sandhi_end_jd: w.end_jd,
+            sandhi_start: fmt_date(w.start_jd),
+            sandhi_end: fmt_date(w.end_jd),
+        }
+    })
+    .collect();
+
+    Ok(serde_wasm_bindgen::to_value(&events)?)
+}
+
+/// Daily panchang for `days` civil days from a local date
+///
+/// `days` may be at most 366. `options` is a `KundliOptions`
+/// object (or undefined); only the compatibility settings are
+/// used. Times are in the given time zone.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn daily_panchang(
+    year: i32,
+    month: u8,
+    day: u8,
+    days: u32,
+    tz_offset_hours: f64,
+    latitude_deg: f64,
+    longitude_deg: f64,
+    options: JsValue,
+) -> Result<JsValue, JsValue> {
+    if days > MAX_PANCHANG_DAYS {
+        return Err(JsValue::from_str(&format!(
+            "daily panchang covers at most {MAX_PANCHANG_DAYS} days, got {days}"
+        )));
+    }
+
+    let options: KundliOptions = if options.is_undefined() || options.is_null() {
+        KundliOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)?
+    };
+    let compat = options.compatibility().map_err(|e| JsValue::from_str(&e))?;
+
+    let result: Vec<DailyPanchangJS> = panchang::daily::daily_panchang(
+        year,
+        month,
+        day,
+        days as usize,
+        tz_offset_hours,
+        latitude_deg.to_radians(),
+        longitude_deg.to_radians(),
+        &compat,
+    )
+    .iter()
+    .map(|d| daily_panchang_js(d, tz_offset_hours))
+    .collect();
+
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
+/// Register a custom varga mapping table under `name`
+///
+/// `signs[sign * division + amsha]` is the varga sign (0 = Aries)
+/// of each equal amsha; the name can then be used in
+/// `KundliOptions::varga_schemes`.
+#[wasm_bindgen(js_name = register_varga_table)]
+pub fn register_varga_table_js(name: &str, division: u8, signs: Vec<u8>) -> Result<(), JsValue> {
+    register_varga_table(name, division, signs)
+        .map(|_| ())
+        .map_err(|e| JsValue::from_str(&format!("invalid varga table: {e:?}")))
+}
+
+fn build_kundli(
+    input: DateTimeInput,
+    latitude_deg: f64,
+    longitude_deg: f64,
+    varga_schemes: &VargaSchemes,
+    dasha_config: DashaConfig,
+    compat: &Compatibility,
+    sections: KundliSections,
+) -> KundliJS {
+    // ---------- TIME ----------
+    let jd_tt = jd_tt_from_datetime(input);
+
     let jd_ut = jd_ut_from_tt(jd_tt);
 
     // ---------- AYANAMSA ----------
-    let ayan = lahiri_ayanamsa(jd_tt);
+    let ayan = compat.ayanamsa(jd_tt);
 
     // ---------- MOON ----------
     let moon_trop = moon_longitude_elp(jd_tt);
-    let moon_sid = normalize(moon_trop - ayan);
+    // Moon for the nakshatra and dashas (true or mean)
+    let moon_sid = compat.moon_sidereal_deg(jd_tt).to_radians();
     // use for mean mmoon calculation
     // let moon_mean_tropical = moon_mean_longitude_tropical(jd_tt);
     // let moon_mean_sidereal = normalize(moon_mean_tropical - ayan);
@@ -181,7 +1385,8 @@ pub fn generate_kundli(
     let planets: Vec<PlanetJS> = raw_planets
         .into_iter()
         .map(|(name, trop)| {
-            let sid = normalize(trop - ayan);
+            let sid = compat.sidereal(trop, jd_tt);
+            let trop = compat.apparent(trop, jd_tt);
             PlanetJS {
                 name: name.to_string(),
                 tropical_deg: trop.to_degrees(),
@@ -194,7 +1399,19 @@ pub fn generate_kundli(
     let lat = latitude_deg.to_radians();
     let lon = longitude_deg.to_radians();
 
-    let asc_sid = ascendant_sidereal(jd_ut, lat, lon);
+    let asc_sid = normalize(ascendant_tropical(jd_ut, jd_tt, lat, lon) - ayan);
+
+    let angles_trop = angles_tropical(jd_ut, jd_tt, lat, lon);
+    let angles_sid = angles_trop.to_sidereal(ayan);
+    let angles = AnglesJS {
+        ascendant_deg: angles_sid.ascendant.to_degrees(),
+        descendant_deg: angles_sid.descendant.to_degrees(),
+        mc_deg: angles_sid.mc.to_degrees(),
+        ic_deg: angles_sid.ic.to_degrees(),
+        vertex_deg: angles_sid.vertex.to_degrees(),
+        anti_vertex_deg: angles_sid.anti_vertex.to_degrees(),
+        east_point_deg: angles_sid.east_point.to_degrees(),
+    };
 
     let houses: Vec<HouseJS> = whole_sign_houses(asc_sid)
         .into_iter()
@@ -204,74 +1421,239 @@ pub fn generate_kundli(
         })
         .collect();
 
-    // ---------- DASHAS ----------
-    let mahadashas = mahadasha_timeline(jd_tt, moon_sid, DashaMode::Astronomical)
-        .into_iter()
-        .map(|d| DashaPeriodJS {
-            maha: lord_name(d.lord),
-            antara: None,
-            praty: None,
-            start_jd: d.start_jd,
-            end_jd: d.end_jd,
-            start_date: fmt_date(d.start_jd),
-            end_date: fmt_date(d.end_jd),
-        })
-        .collect();
+    // ---------- BHAVA CHALIT ----------
+    let sripati = sripati_bhavas(angles_sid.ascendant, angles_sid.mc);
 
-    let antardashas = antardasha_timeline(jd_tt, moon_sid, DashaMode::Astronomical)
-        .into_iter()
-        .map(|d| DashaPeriodJS {
-            maha: lord_name(d.maha),
-            antara: Some(lord_name(d.antara)),
-            praty: None,
-            start_jd: d.start_jd,
-            end_jd: d.end_jd,
-            start_date: fmt_date(d.start_jd),
-            end_date: fmt_date(d.end_jd),
+    let bhavas = sripati
+        .iter()
+        .map(|b| BhavaJS {
+            number: b.number,
+            madhya_deg: b.madhya.to_degrees(),
+            start_sandhi_deg: b.start_sandhi.to_degrees(),
+            end_sandhi_deg: b.end_sandhi.to_degrees(),
         })
         .collect();
 
-    let pratyantardashas = pratyantardasha_timeline(jd_tt, moon_sid, DashaMode::Astronomical)
+    let bhava_chalit = bhava_chalit(asc_sid.to_degrees(), &planets, &sripati)
         .into_iter()
-        .map(|d| DashaPeriodJS {
-            maha: lord_name(d.maha),
-            antara: Some(lord_name(d.antara)),
-            praty: Some(lord_name(d.praty)),
-            start_jd: d.start_jd,
-            end_jd: d.end_jd,
-            start_date: fmt_date(d.start_jd),
-            end_date: fmt_date(d.end_jd),
+        .map(|p| BhavaPlacementJS {
+            planet: p.planet,
+            rashi_house: p.rashi_house,
+            bhava: p.bhava,
+            shifted: p.shifted,
         })
         .collect();
 
-    // ---------- DIVISIONAL D1–D30 ----------
-    let mut divisional_charts = Vec::new();
+    // ---------- KP ----------
+    let (kp_planets_js, kp_cusps_js) = if sections.kp {
+        let kp_planets_js = kp_planets(&planets).into_iter().map(kp_point_js).collect();
+
+        let kp_cusps_js = match house_cusps(
+            HouseSystem::Placidus,
+            angles_trop.ascendant,
+            angles_trop.mc,
+            lat,
+            nutation(jd_tt).eps_true,
+        ) {
+            Ok(cusps) => {
+                let cusps_deg = cusps.map(|c| normalize(c - ayan).to_degrees());
+                kp_cusps(&cusps_deg).into_iter().map(kp_point_js).collect()
+            }
+            Err(_) => Vec::new(),
+        };
+
+        (kp_planets_js, kp_cusps_js)
+    } else {
+        (Vec::new(), Vec::new())
+    };
+
+    // ---------- DASHAS ----------
+    let rashi_chart = RashiChart::new(asc_sid.to_degrees(), &planets);
+    let clock = DashaClock::with_compat(jd_tt, dasha_config.year, compat);
+    let seed_deg =
+        dasha_config
+            .seed
+            .longitude(moon_sid.to_degrees(), asc_sid.to_degrees(), &rashi_chart);
 
-    for div in 1..=30 {
-        let chart = divisional_chart(div, &planets);
+    let (mahadashas, antardashas, pratyantardashas) = match dasha_config.system {
+        DashaSystem::Vimshottari => nakshatra_dashas(&VIMSHOTTARI, &clock, seed_deg),
+        DashaSystem::Yogini => yogini_dashas(&clock, seed_deg),
+        DashaSystem::Ashtottari => nakshatra_dashas(&ASHTOTTARI, &clock, seed_deg),
+        DashaSystem::Conditional(c) => nakshatra_dashas(c.system(), &clock, seed_deg),
+        DashaSystem::Chara(variant) => sign_dashas(
+            chara_dasha(&clock, &rashi_chart, variant),
+            chara_antardashas(&clock, &rashi_chart, variant),
+        ),
+        DashaSystem::Narayana(division) => {
+            let chart = if division <= 1 {
+                rashi_chart
+            } else {
+                RashiChart::varga(
+                    division,
+                    asc_sid.to_degrees(),
+                    &planets,
+                    varga_schemes.get(division),
+                )
+            };
+            sign_dashas(
+                narayana_dasha(&clock, &chart),
+                narayana_antardashas(&clock, &chart),
+            )
+        }
+        DashaSystem::Sthira => sign_dashas(
+            sthira_dasha(&clock, &rashi_chart),
+            sthira_antardashas(&clock, &rashi_chart),
+        ),
+        DashaSystem::Shoola => sign_dashas(
+            shoola_dasha(&clock, &rashi_chart),
+            shoola_antardashas(&clock, &rashi_chart),
+        ),
+        DashaSystem::NiryanaShoola => sign_dashas(
+            niryana_shoola_dasha(&clock, &rashi_chart),
+            niryana_shoola_antardashas(&clock, &rashi_chart),
+        ),
+        DashaSystem::Drig => sign_dashas(
+            drig_dasha(&clock, &rashi_chart),
+            drig_antardashas(&clock, &rashi_chart),
+        ),
+        DashaSystem::Kalachakra => kalachakra_dashas(&clock, moon_sid.to_degrees()),
+    };
+
+    let dasha_balance = mahadashas
+        .iter()
+        .find(|m| jd_tt >= m.start_jd && jd_tt < m.end_jd)
+        .map(|m| dasha_balance_js(m.maha.clone(), clock.years(m.end_jd), dasha_config.year));
 
-        let planets_js = chart
-            .planets
+    let kalachakra = sections.kalachakra.then(|| {
+        let kc = kalachakra_birth(moon_sid.to_degrees());
+        KalachakraJS {
+            chakra: kc.pada.chakra.name().to_string(),
+            nakshatra: kc.nakshatra_name.to_string(),
+            pada: kc.pada.pada,
+            deha: Sign::from_index(kc.pada.deha).name().to_string(),
+            jeeva: Sign::from_index(kc.pada.jeeva).name().to_string(),
+            paramayus: kc.pada.paramayus,
+        }
+    });
+
+    let mut applicable_dashas = Vec::new();
+    if ashtottari_applicability(asc_sid.to_degrees(), &planets).is_some_and(|a| a.applies()) {
+        applicable_dashas.push(DashaSystem::Ashtottari.name().to_string());
+    }
+    for c in ConditionalDasha::ALL {
+        if c.applies(asc_sid.to_degrees(), &planets) == Some(true) {
+            applicable_dashas.push(c.name().to_string());
+        }
+    }
+
+    // ---------- DIVISIONAL D1–D30, D40–D150 ----------
+    let mut divisional_charts = Vec::new();
+
+    if sections.vargas {
+        let sun_sid_deg = planets[0].sidereal_deg; // Sun is first in `raw_planets`
+        let special_points: Vec<PlanetJS> = aprakasha_grahas(sun_sid_deg)
             .into_iter()
-            .map(|p| DivisionalPlanetJS {
-                planet: p.planet,
-                sign: p.sign,
-                degree: p.degree,
+            .map(|(name, sid)| PlanetJS {
+                name: name.to_string(),
+                tropical_deg: (sid.to_radians() + ayan).to_degrees() % 360.0,
+                sidereal_deg: sid,
             })
             .collect();
 
-        divisional_charts.push(DivisionalChartJS {
-            division: div,
-            planets: planets_js,
-        });
+        for div in (1..=30).chain([40, 45, 60, 81, 108, 144, 150]) {
+            let chart = divisional_chart_with_lagna(
+                div,
+                &planets,
+                asc_sid.to_degrees(),
+                &special_points,
+                varga_schemes.get(div),
+            );
+
+            divisional_charts.push(DivisionalChartJS {
+                division: div,
+                ascendant: chart.ascendant.map(divisional_planet_js),
+                houses: chart
+                    .houses
+                    .into_iter()
+                    .map(|h| HouseJS {
+                        number: h.number,
+                        sign: h.sign.name().to_string(),
+                    })
+                    .collect(),
+                planets: chart
+                    .planets
+                    .into_iter()
+                    .map(divisional_planet_js)
+                    .collect(),
+                special_points: chart
+                    .special_points
+                    .into_iter()
+                    .map(divisional_planet_js)
+                    .collect(),
+            });
+        }
     }
 
+    // ---------- VIMSHOPAKA ----------
+    let vimshopaka = if sections.vimshopaka {
+        let [shad, sapta, dasha, shodasha] = [
+            VargaGroup::Shadvarga,
+            VargaGroup::Saptavarga,
+            VargaGroup::Dashavarga,
+            VargaGroup::Shodashavarga,
+        ]
+        .map(|g| vimshopaka_bala(&planets, g, varga_schemes));
+
+        shodasha
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| VimshopakaJS {
+                planet: lord_name(v.planet),
+                vargottama: v.vargottama,
+
+                shadvarga: shad[i].points,
+                saptavarga: sapta[i].points,
+                dashavarga: dasha[i].points,
+                shodashavarga: v.points,
+
+                saptavarga_amsa: sapta[i].amsa.map(str::to_string),
+                dashavarga_amsa: dasha[i].amsa.map(str::to_string),
+                shodashavarga_amsa: v.amsa.map(str::to_string),
+
+                vargas: v
+                    .vargas
+                    .iter()
+                    .map(|d| VargaDignityJS {
+                        division: d.division,
+                        sign: Sign::from_index(d.sign).name().to_string(),
+                        dignity: d.dignity.name().to_string(),
+                        points: d.points,
+                    })
+                    .collect(),
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // ---------- PANCHANG ----------
+    let panchang = sections.panchang.then(|| {
+        let p = panchang_at(
+            jd_tt,
+            latitude_deg.to_radians(),
+            longitude_deg.to_radians(),
+            compat,
+        );
+        panchang_js(&p, input.tz_offset_hours)
+    });
+
     // ---------- OUTPUT ----------
-    let result = KundliJS {
+    KundliJS {
         jd_tt,
         jd_ut,
 
         ascendant_sidereal_deg: asc_sid.to_degrees(),
+        angles,
 
         moon_sidereal_deg: moon_sid.to_degrees(),
         nakshatra: nak.name.to_string(),
@@ -281,12 +1663,33 @@ pub fn generate_kundli(
         planets,
         houses,
 
+        bhavas,
+        bhava_chalit,
+
+        kp_planets: kp_planets_js,
+        kp_cusps: kp_cusps_js,
+
+        dasha_system: dasha_config.system.name().to_string(),
+        dasha_year: dasha_config.year.name().to_string(),
+        dasha_seed: dasha_config.seed.name().to_string(),
+        dasha_seed_deg: seed_deg,
+        applicable_dashas,
         mahadashas,
         antardashas,
         pratyantardashas,
+        dasha_balance,
+        kalachakra,
 
         divisional_charts,
-    };
+        vimshopaka,
 
-    serde_wasm_bindgen::to_value(&result).unwrap()
+        compatibility: CompatibilityJS {
+            ayanamsa: compat.ayanamsa.name().to_string(),
+            ayanamsa_deg: ayan.to_degrees(),
+            moon: compat.moon.name().to_string(),
+            nutation: compat.nutation.name().to_string(),
+        },
+
+        panchang,
+    }
 }
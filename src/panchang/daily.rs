@@ -0,0 +1,259 @@
+// ======================================================
+// Daily Panchang
+// ======================================================
+//
+// One entry per civil day at a location. Sun and Moon rise and
+// set are the events of the civil day (local midnight to
+// midnight). The limbs and signs are listed from sunrise to the
+// next sunrise, the panchang day, with every change inside it;
+// where the Sun does not rise the civil day is used instead.
+//
+// Ayana and ritu follow the sidereal Sun (solar months):
+// Uttarayana from Makara sankranti, and two signs per ritu
+// from Vasanta in Mina and Mesha.
+
+use crate::compat::Compatibility;
+use crate::ephemeris::moon_elp::moon_longitude_elp;
+use crate::panchang::limbs::{LimbPeriod, LunarLimb, lunar_limbs_between, parts_between};
+use crate::panchang::rise_set::{Body, Horizon, event_after};
+use crate::panchang::{sun_aberrated_lon, vara};
+use crate::time::calendar::jd_to_calendar;
+use crate::time::datetime::{DateTimeInput, jd_tt_from_datetime};
+use crate::time::julian::jd_ut_from_tt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Paksha {
+    /// Bright half (waxing)
+    Shukla,
+    /// Dark half (waning)
+    Krishna,
+}
+
+impl Paksha {
+    pub fn of_tithi(index: usize) -> Self {
+        if index % 30 < 15 {
+            Paksha::Shukla
+        } else {
+            Paksha::Krishna
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Paksha::Shukla => "Shukla",
+            Paksha::Krishna => "Krishna",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ayana {
+    /// Sun in Makara .. Mithuna
+    Uttarayana,
+    /// Sun in Karka .. Dhanu
+    Dakshinayana,
+}
+
+impl Ayana {
+    pub fn of_sun_sign(sign: u8) -> Self {
+        if (sign + 3) % 12 < 6 {
+            Ayana::Uttarayana
+        } else {
+            Ayana::Dakshinayana
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Ayana::Uttarayana => "Uttarayana",
+            Ayana::Dakshinayana => "Dakshinayana",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ritu {
+    Vasanta,
+    Grishma,
+    Varsha,
+    Sharad,
+    Hemanta,
+    Shishira,
+}
+
+impl Ritu {
+    /// Ritu of the sidereal Sun's sign (0 = Aries)
+    pub fn of_sun_sign(sign: u8) -> Self {
+        match ((sign + 1) % 12) / 2 {
+            0 => Ritu::Vasanta,
+            1 => Ritu::Grishma,
+            2 => Ritu::Varsha,
+            3 => Ritu::Sharad,
+            4 => Ritu::Hemanta,
+            _ => Ritu::Shishira,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Ritu::Vasanta => "Vasanta",
+            Ritu::Grishma => "Grishma",
+            Ritu::Varsha => "Varsha",
+            Ritu::Sharad => "Sharad",
+            Ritu::Hemanta => "Hemanta",
+            Ritu::Shishira => "Shishira",
+        }
+    }
+}
+
+/// Sidereal sign (0 = Aries) held over a period
+#[derive(Debug, Clone, Copy)]
+pub struct SignPeriod {
+    pub sign: u8,
+    pub start_jd: f64,
+    pub end_jd: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DailyPanchang {
+    /// Local civil date
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    /// Local midnight starting the day (JD TT)
+    pub midnight_jd: f64,
+
+    pub sunrise: Option<f64>,
+    pub sunset: Option<f64>,
+    pub moonrise: Option<f64>,
+    pub moonset: Option<f64>,
+
+    /// Weekday running at sunrise (None without a sunrise)
+    pub vara: Option<LimbPeriod>,
+    /// Limbs from sunrise to the next sunrise, in order
+    pub tithis: Vec<LimbPeriod>,
+    pub nakshatras: Vec<LimbPeriod>,
+    pub yogas: Vec<LimbPeriod>,
+    pub karanas: Vec<LimbPeriod>,
+
+    pub sun_signs: Vec<SignPeriod>,
+    pub moon_signs: Vec<SignPeriod>,
+
+    /// At sunrise (midnight without a sunrise)
+    pub paksha: Paksha,
+    pub ayana: Ayana,
+    pub ritu: Ritu,
+}
+
+/// Panchang for `days` civil days from the given local date
+///
+/// Input:
+/// - year, month, day : first local date
+/// - tz_offset_hours  : local time zone (e.g. +5.5 for IST)
+/// - latitude_rad, longitude_rad : observer (east positive)
+#[allow(clippy::too_many_arguments)]
+pub fn daily_panchang(
+    year: i32,
+    month: u8,
+    day: u8,
+    days: usize,
+    tz_offset_hours: f64,
+    latitude_rad: f64,
+    longitude_rad: f64,
+    compat: &Compatibility,
+) -> Vec<DailyPanchang> {
+    let first_midnight = jd_tt_from_datetime(DateTimeInput {
+        year,
+        month,
+        day,
+        hour: 0,
+        minute: 0,
+        second: 0.0,
+        tz_offset_hours,
+    });
+
+    (0..days)
+        .map(|i| {
+            panchang_day(
+                first_midnight + i as f64,
+                tz_offset_hours,
+                latitude_rad,
+                longitude_rad,
+                compat,
+            )
+        })
+        .collect()
+}
+
+fn panchang_day(
+    midnight_jd: f64,
+    tz_offset_hours: f64,
+    latitude_rad: f64,
+    longitude_rad: f64,
+    compat: &Compatibility,
+) -> DailyPanchang {
+    let event = |body, kind| event_after(body, kind, midnight_jd, 1.0, latitude_rad, longitude_rad);
+
+    let sunrise = event(Body::Sun, Horizon::Rise);
+
+    // Panchang day: sunrise to the next sunrise
+    let from = sunrise.unwrap_or(midnight_jd);
+    let to = sunrise
+        .and_then(|r| {
+            event_after(
+                Body::Sun,
+                Horizon::Rise,
+                r + 0.5,
+                1.0,
+                latitude_rad,
+                longitude_rad,
+            )
+        })
+        .unwrap_or(midnight_jd + 1.0);
+
+    let limbs = |limb| lunar_limbs_between(limb, from, to, compat);
+    let tithis = limbs(LunarLimb::Tithi);
+
+    let sun_sid = |jd| compat.sidereal(sun_aberrated_lon(jd), jd).to_degrees();
+    let moon_sid = |jd| compat.sidereal(moon_longitude_elp(jd), jd).to_degrees();
+    let signs = |angle: &dyn Fn(f64) -> f64, rate| -> Vec<SignPeriod> {
+        parts_between(angle, 30.0, rate, from, to)
+            .into_iter()
+            .map(|(sign, start_jd, end_jd)| SignPeriod {
+                sign: sign as u8,
+                start_jd,
+                end_jd,
+            })
+            .collect()
+    };
+    let sun_signs = signs(&sun_sid, 0.985647);
+    let moon_signs = signs(&moon_sid, 13.176358);
+
+    // Local date, read a few hours after midnight
+    let local = jd_to_calendar(jd_ut_from_tt(midnight_jd) + tz_offset_hours / 24.0 + 0.25);
+
+    DailyPanchang {
+        year: local.year,
+        month: local.month,
+        day: local.day,
+        midnight_jd,
+
+        sunrise,
+        sunset: event(Body::Sun, Horizon::Set),
+        moonrise: event(Body::Moon, Horizon::Rise),
+        moonset: event(Body::Moon, Horizon::Set),
+
+        vara: sunrise.and_then(|r| vara(r + 1e-4, latitude_rad, longitude_rad)),
+        paksha: Paksha::of_tithi(tithis[0].index),
+        ayana: Ayana::of_sun_sign(sun_signs[0].sign),
+        ritu: Ritu::of_sun_sign(sun_signs[0].sign),
+
+        tithis,
+        nakshatras: limbs(LunarLimb::Nakshatra),
+        yogas: limbs(LunarLimb::Yoga),
+        karanas: limbs(LunarLimb::Karana),
+
+        sun_signs,
+        moon_signs,
+    }
+}
@@ -0,0 +1,336 @@
+// ======================================================
+// Lunar Limbs — tithi, nakshatra, yoga, karana
+// ======================================================
+//
+// Each lunar limb is an angle divided into equal parts:
+// - tithi     : Moon − Sun, 12°
+// - karana    : Moon − Sun, 6°
+// - nakshatra : sidereal Moon, 13°20′
+// - yoga      : sidereal Sun + sidereal Moon, 13°20′
+//
+// The angles only ever grow, so the start and end of a part
+// are found by a secant search for the moment the angle
+// crosses its boundary. Times are Julian Days (TT).
+
+use crate::astrology::nakshatra::NAKSHATRA_NAMES;
+use crate::compat::Compatibility;
+use crate::ephemeris::moon_elp::moon_longitude_elp;
+use crate::panchang::sun_aberrated_lon;
+
+/// The five limbs of the panchang
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limb {
+    Tithi,
+    Vara,
+    Nakshatra,
+    Yoga,
+    Karana,
+}
+
+impl Limb {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Limb::Tithi => "Tithi",
+            Limb::Vara => "Vara",
+            Limb::Nakshatra => "Nakshatra",
+            Limb::Yoga => "Yoga",
+            Limb::Karana => "Karana",
+        }
+    }
+
+    /// The limb as a lunar limb; None for vara, which depends on
+    /// sunrise (see `panchang::vara`)
+    pub fn lunar(&self) -> Option<LunarLimb> {
+        match self {
+            Limb::Tithi => Some(LunarLimb::Tithi),
+            Limb::Vara => None,
+            Limb::Nakshatra => Some(LunarLimb::Nakshatra),
+            Limb::Yoga => Some(LunarLimb::Yoga),
+            Limb::Karana => Some(LunarLimb::Karana),
+        }
+    }
+}
+
+/// The four limbs that are angles of the Sun and Moon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LunarLimb {
+    Tithi,
+    Nakshatra,
+    Yoga,
+    Karana,
+}
+
+impl LunarLimb {
+    pub fn limb(&self) -> Limb {
+        match self {
+            LunarLimb::Tithi => Limb::Tithi,
+            LunarLimb::Nakshatra => Limb::Nakshatra,
+            LunarLimb::Yoga => Limb::Yoga,
+            LunarLimb::Karana => Limb::Karana,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.limb().name()
+    }
+
+    /// Width of one part and mean daily motion of the angle
+    /// (degrees)
+    fn motion(&self) -> (f64, f64) {
+        match self {
+            LunarLimb::Tithi => (12.0, 12.190749),
+            LunarLimb::Karana => (6.0, 12.190749),
+            LunarLimb::Nakshatra => (360.0 / 27.0, 13.176358),
+            LunarLimb::Yoga => (360.0 / 27.0, 14.162005),
+        }
+    }
+}
+
+/// Tithi names; 0..14 in the bright half, 15..29 in the dark half
+pub const TITHI_NAMES: [&str; 30] = [
+    "Pratipada",
+    "Dvitiya",
+    "Tritiya",
+    "Chaturthi",
+    "Panchami",
+    "Shashthi",
+    "Saptami",
+    "Ashtami",
+    "Navami",
+    "Dashami",
+    "Ekadashi",
+    "Dvadashi",
+    "Trayodashi",
+    "Chaturdashi",
+    "Purnima",
+    "Pratipada",
+    "Dvitiya",
+    "Tritiya",
+    "Chaturthi",
+    "Panchami",
+    "Shashthi",
+    "Saptami",
+    "Ashtami",
+    "Navami",
+    "Dashami",
+    "Ekadashi",
+    "Dvadashi",
+    "Trayodashi",
+    "Chaturdashi",
+    "Amavasya",
+];
+
+pub const YOGA_NAMES: [&str; 27] = [
+    "Vishkambha",
+    "Priti",
+    "Ayushman",
+    "Saubhagya",
+    "Shobhana",
+    "Atiganda",
+    "Sukarma",
+    "Dhriti",
+    "Shula",
+    "Ganda",
+    "Vriddhi",
+    "Dhruva",
+    "Vyaghata",
+    "Harshana",
+    "Vajra",
+    "Siddhi",
+    "Vyatipata",
+    "Variyana",
+    "Parigha",
+    "Shiva",
+    "Siddha",
+    "Sadhya",
+    "Shubha",
+    "Shukla",
+    "Brahma",
+    "Indra",
+    "Vaidhriti",
+];
+
+/// Weekdays from Sunday
+pub const VARA_NAMES: [&str; 7] = [
+    "Ravivara",
+    "Somavara",
+    "Mangalavara",
+    "Budhavara",
+    "Guruvara",
+    "Shukravara",
+    "Shanivara",
+];
+
+/// Movable karanas, repeating eight times from the 2nd half-tithi
+const MOVABLE_KARANAS: [&str; 7] = [
+    "Bava", "Balava", "Kaulava", "Taitila", "Garaja", "Vanija", "Vishti",
+];
+
+/// Karana of a half-tithi (0..59, 0 = first half of Shukla Pratipada)
+pub fn karana_name(index: usize) -> &'static str {
+    match index % 60 {
+        0 => "Kimstughna",
+        57 => "Shakuni",
+        58 => "Chatushpada",
+        59 => "Naga",
+        i => MOVABLE_KARANAS[(i - 1) % 7],
+    }
+}
+
+/// One part of a limb with its start and end
+#[derive(Debug, Clone, Copy)]
+pub struct LimbPeriod {
+    pub limb: Limb,
+    /// Part index from 0 (tithi 0..29, karana 0..59,
+    /// nakshatra and yoga 0..26, vara 0 = Sunday)
+    pub index: usize,
+    pub name: &'static str,
+    pub start_jd: f64,
+    pub end_jd: f64,
+}
+
+impl LimbPeriod {
+    pub fn contains(&self, jd: f64) -> bool {
+        jd >= self.start_jd && jd < self.end_jd
+    }
+}
+
+/// Angle counted by a lunar limb (degrees, 0..360)
+fn limb_angle(limb: LunarLimb, jd_tt: f64, compat: &Compatibility) -> f64 {
+    let moon = moon_longitude_elp(jd_tt);
+    let sun = sun_aberrated_lon(jd_tt);
+
+    let angle = match limb {
+        LunarLimb::Tithi | LunarLimb::Karana => moon - sun,
+        LunarLimb::Nakshatra => compat.sidereal(moon, jd_tt),
+        LunarLimb::Yoga => compat.sidereal(sun, jd_tt) + compat.sidereal(moon, jd_tt),
+    };
+
+    normalize_deg(angle.to_degrees())
+}
+
+/// Name of part `index` of a lunar limb
+fn part_name(limb: LunarLimb, index: usize) -> &'static str {
+    match limb {
+        LunarLimb::Tithi => TITHI_NAMES[index % 30],
+        LunarLimb::Karana => karana_name(index),
+        LunarLimb::Nakshatra => NAKSHATRA_NAMES[index % 27],
+        LunarLimb::Yoga => YOGA_NAMES[index % 27],
+    }
+}
+
+/// Part of a lunar limb running at `jd_tt`, with its start and end
+pub fn lunar_limb(limb: LunarLimb, jd_tt: f64, compat: &Compatibility) -> LimbPeriod {
+    lunar_limbs_between(limb, jd_tt, jd_tt, compat)[0]
+}
+
+/// Parts of a lunar limb overlapping `from_jd .. to_jd`, in order
+pub fn lunar_limbs_between(
+    limb: LunarLimb,
+    from_jd: f64,
+    to_jd: f64,
+    compat: &Compatibility,
+) -> Vec<LimbPeriod> {
+    let (span, rate) = limb.motion();
+    let angle = |jd| limb_angle(limb, jd, compat);
+
+    parts_between(&angle, span, rate, from_jd, to_jd)
+        .into_iter()
+        .map(|(index, start_jd, end_jd)| LimbPeriod {
+            limb: limb.limb(),
+            index,
+            name: part_name(limb, index),
+            start_jd,
+            end_jd,
+        })
+        .collect()
+}
+
+/// Equal parts of a steadily growing angle (degrees) overlapping
+/// `from_jd .. to_jd`, as (index, start, end)
+///
+/// `rate` is the angle's mean daily motion (degrees / day).
+pub(crate) fn parts_between(
+    angle: &dyn Fn(f64) -> f64,
+    span: f64,
+    rate: f64,
+    from_jd: f64,
+    to_jd: f64,
+) -> Vec<(usize, f64, f64)> {
+    let count = (360.0 / span).round() as usize;
+
+    let now = angle(from_jd);
+    let index = (now / span).floor() as usize % count;
+    let start = index as f64 * span;
+
+    let mut out = vec![(
+        index,
+        crossing(angle, rate, start, from_jd - (now - start) / rate),
+        crossing(
+            angle,
+            rate,
+            start + span,
+            from_jd + (start + span - now) / rate,
+        ),
+    )];
+
+    while out[out.len() - 1].2 < to_jd {
+        let (prev, _, start_jd) = out[out.len() - 1];
+
+        let index = (prev + 1) % count;
+        let end = (index + 1) as f64 * span;
+
+        out.push((
+            index,
+            start_jd,
+            crossing(angle, rate, end, start_jd + span / rate),
+        ));
+    }
+
+    out
+}
+
+/// Moment near `guess_jd` when `angle` equals `target` (degrees),
+/// by the secant method
+fn crossing(angle: &dyn Fn(f64) -> f64, mean_rate: f64, target: f64, guess_jd: f64) -> f64 {
+    let diff = |jd: f64| wrap_180(angle(jd) - target);
+
+    let mut a = guess_jd;
+    let mut fa = diff(a);
+    let mut b = a - fa / mean_rate;
+
+    for _ in 0..20 {
+        let fb = diff(b);
+        if fb.abs() < 1e-8 {
+            break;
+        }
+
+        let slope = (fb - fa) / (b - a);
+        let rate = if slope.is_finite() && slope > 0.0 {
+            slope
+        } else {
+            mean_rate
+        };
+
+        a = b;
+        fa = fb;
+        b -= fb / rate;
+    }
+
+    b
+}
+
+#[inline]
+fn wrap_180(x: f64) -> f64 {
+    let x = normalize_deg(x);
+    if x > 180.0 { x - 360.0 } else { x }
+}
+
+#[inline]
+fn normalize_deg(mut x: f64) -> f64 {
+    x %= 360.0;
+    if x < 0.0 {
+        x += 360.0;
+    }
+    x
+}
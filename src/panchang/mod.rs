@@ -0,0 +1,101 @@
+// ======================================================
+// Panchang — the five limbs of the day
+// ======================================================
+//
+// Tithi, vara, nakshatra, yoga and karana at a moment, each
+// with the times it starts and ends. Vara runs from sunrise to
+// sunrise, so it needs the observer's location.
+//
+// The limbs always use the true (ELP) Moon and the apparent
+// Sun; ayanamsa and nutation follow `Compatibility`.
+
+pub mod daily;
+pub mod limbs;
+pub mod rise_set;
+
+use crate::compat::Compatibility;
+use crate::ephemeris::planets::sun_lon;
+use crate::ephemeris::precession::general_precession_lon;
+use crate::time::julian::jd_ut_from_tt;
+
+use limbs::{Limb, LimbPeriod, LunarLimb, VARA_NAMES, lunar_limb};
+use rise_set::{Body, Horizon, event_after, event_before};
+
+/// Annual aberration of the Sun (radians)
+const SUN_ABERRATION: f64 = 20.4898 / 3600.0 * std::f64::consts::PI / 180.0;
+
+/// The five limbs running at one moment
+#[derive(Debug, Clone, Copy)]
+pub struct Panchang {
+    pub jd_tt: f64,
+    pub tithi: LimbPeriod,
+    /// None where the Sun does not rise (polar day or night)
+    pub vara: Option<LimbPeriod>,
+    pub nakshatra: LimbPeriod,
+    pub yoga: LimbPeriod,
+    pub karana: LimbPeriod,
+}
+
+/// Panchang at `jd_tt` for an observer (radians, east positive)
+pub fn panchang_at(
+    jd_tt: f64,
+    latitude_rad: f64,
+    longitude_rad: f64,
+    compat: &Compatibility,
+) -> Panchang {
+    Panchang {
+        jd_tt,
+        tithi: lunar_limb(LunarLimb::Tithi, jd_tt, compat),
+        vara: vara(jd_tt, latitude_rad, longitude_rad),
+        nakshatra: lunar_limb(LunarLimb::Nakshatra, jd_tt, compat),
+        yoga: lunar_limb(LunarLimb::Yoga, jd_tt, compat),
+        karana: lunar_limb(LunarLimb::Karana, jd_tt, compat),
+    }
+}
+
+/// Weekday running at `jd_tt`, from the sunrise before it to the
+/// next one
+pub fn vara(jd_tt: f64, latitude_rad: f64, longitude_rad: f64) -> Option<LimbPeriod> {
+    let start_jd = event_before(
+        Body::Sun,
+        Horizon::Rise,
+        jd_tt,
+        1.5,
+        latitude_rad,
+        longitude_rad,
+    )?;
+    let end_jd = event_after(
+        Body::Sun,
+        Horizon::Rise,
+        jd_tt,
+        1.5,
+        latitude_rad,
+        longitude_rad,
+    )?;
+
+    let index = weekday(start_jd, longitude_rad);
+
+    Some(LimbPeriod {
+        limb: Limb::Vara,
+        index,
+        name: VARA_NAMES[index],
+        start_jd,
+        end_jd,
+    })
+}
+
+/// Weekday (0 = Sunday) of the local date at `jd_tt`, by local
+/// mean time
+pub fn weekday(jd_tt: f64, longitude_rad: f64) -> usize {
+    let local = jd_ut_from_tt(jd_tt) + longitude_rad.to_degrees() / 360.0;
+    ((local + 0.5).floor() as i64 + 1).rem_euclid(7) as usize
+}
+
+/// Sun's tropical longitude (radians, mean equinox of date)
+/// corrected for aberration
+///
+/// `sun_lon` is referred to the J2000 equinox; the general
+/// precession carries it to the equinox of date the ELP Moon uses.
+pub(crate) fn sun_aberrated_lon(jd_tt: f64) -> f64 {
+    sun_lon(jd_tt) + general_precession_lon(jd_tt) - SUN_ABERRATION
+}
@@ -0,0 +1,194 @@
+// ======================================================
+// Rising & Setting
+// ======================================================
+//
+// Rise and set are the moments the body's altitude crosses a
+// fixed value:
+// - Sun  : −0.8333° (upper limb, with standard refraction)
+// - Moon : +0.125° (upper limb and refraction, less the mean
+//          horizontal parallax; Meeus ch. 15)
+// The altitude is sampled hourly and each change of sign
+// refined by bisection, which also copes with polar days and
+// nights and with days without a moonrise (no crossing).
+//
+// Times are Julian Days (TT); latitude and longitude in
+// RADIANS (east positive).
+
+use crate::ephemeris::moon_elp::moon_longitude_elp;
+use crate::frames::nutation::nutation;
+use crate::panchang::sun_aberrated_lon;
+use crate::time::julian::jd_ut_from_tt;
+use crate::time::sidereal::local_apparent_sidereal_time;
+
+use std::f64::consts::PI;
+
+// Search step and precision (days)
+const STEP: f64 = 1.0 / 24.0;
+const PRECISION: f64 = 1e-6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Body {
+    Sun,
+    Moon,
+}
+
+impl Body {
+    /// Altitude at rise and set (degrees)
+    fn horizon_deg(&self) -> f64 {
+        match self {
+            Body::Sun => -0.8333,
+            Body::Moon => 0.125,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Horizon {
+    Rise,
+    Set,
+}
+
+/// Altitude (radians) of the body's centre at `jd_tt` for an
+/// observer, without refraction
+pub fn body_altitude(body: Body, jd_tt: f64, latitude_rad: f64, longitude_rad: f64) -> f64 {
+    let n = nutation(jd_tt);
+
+    let (lon, lat) = match body {
+        // Ecliptic latitude of the Sun is below 1″
+        Body::Sun => (sun_aberrated_lon(jd_tt), 0.0),
+        Body::Moon => (moon_longitude_elp(jd_tt), moon_latitude(jd_tt)),
+    };
+    let lon = lon + n.delta_psi;
+    let eps = n.eps_true;
+
+    let ra = (lon.sin() * eps.cos() - lat.tan() * eps.sin()).atan2(lon.cos());
+    let dec = (lat.sin() * eps.cos() + lat.cos() * eps.sin() * lon.sin()).asin();
+
+    let lst = local_apparent_sidereal_time(jd_ut_from_tt(jd_tt), jd_tt, longitude_rad);
+    let hour_angle = lst - ra;
+
+    (latitude_rad.sin() * dec.sin() + latitude_rad.cos() * dec.cos() * hour_angle.cos()).asin()
+}
+
+/// First rise or set of the body after `jd_tt`, within `days`
+pub fn event_after(
+    body: Body,
+    event: Horizon,
+    jd_tt: f64,
+    days: f64,
+    latitude_rad: f64,
+    longitude_rad: f64,
+) -> Option<f64> {
+    let height =
+        |jd| body_altitude(body, jd, latitude_rad, longitude_rad).to_degrees() - body.horizon_deg();
+    search(event, &height, jd_tt, days, STEP)
+}
+
+/// Last rise or set of the body before `jd_tt`, within `days`
+pub fn event_before(
+    body: Body,
+    event: Horizon,
+    jd_tt: f64,
+    days: f64,
+    latitude_rad: f64,
+    longitude_rad: f64,
+) -> Option<f64> {
+    let height =
+        |jd| body_altitude(body, jd, latitude_rad, longitude_rad).to_degrees() - body.horizon_deg();
+    search(event, &height, jd_tt, days, -STEP)
+}
+
+/// Moon's geocentric ecliptic latitude (radians)
+///
+/// Largest terms of Meeus Table 47.B, good to about 0.01°,
+/// plenty for rising and setting.
+fn moon_latitude(jd_tt: f64) -> f64 {
+    let t = (jd_tt - 2451545.0) / 36525.0;
+
+    let d = (297.8501921 + 445267.1114034 * t).to_radians();
+    let m = (357.5291092 + 35999.0502909 * t).to_radians();
+    let mp = (134.9633964 + 477198.8675055 * t).to_radians();
+    let f = (93.2720950 + 483202.0175233 * t).to_radians();
+
+    // (D, M, M', F, coefficient in 1e-6 degrees)
+    let terms: [(i8, i8, i8, i8, f64); 20] = [
+        (0, 0, 0, 1, 5128122.0),
+        (0, 0, 1, 1, 280602.0),
+        (0, 0, 1, -1, 277693.0),
+        (2, 0, 0, -1, 173237.0),
+        (2, 0, -1, 1, 55413.0),
+        (2, 0, -1, -1, 46271.0),
+        (2, 0, 0, 1, 32573.0),
+        (0, 0, 2, 1, 17198.0),
+        (2, 0, 1, -1, 9266.0),
+        (0, 0, 2, -1, 8822.0),
+        (2, -1, 0, -1, 8216.0),
+        (2, 0, -2, -1, 4324.0),
+        (2, 0, 1, 1, 4200.0),
+        (2, 1, 0, -1, -3359.0),
+        (2, -1, -1, 1, 2463.0),
+        (2, -1, 0, 1, 2211.0),
+        (2, -1, -1, -1, 2065.0),
+        (0, 1, -1, -1, -1870.0),
+        (4, 0, -1, -1, 1828.0),
+        (0, 1, 0, 1, -1794.0),
+    ];
+
+    let sum: f64 = terms
+        .iter()
+        .map(|&(td, tm, tmp, tf, c)| {
+            c * (td as f64 * d + tm as f64 * m + tmp as f64 * mp + tf as f64 * f).sin()
+        })
+        .sum();
+
+    sum * 1e-6 * PI / 180.0
+}
+
+/// First crossing of zero by `height` (rising through zero for
+/// `Rise`) from `jd` over `days`, stepping by `step` (negative:
+/// backwards in time)
+pub(crate) fn search(
+    event: Horizon,
+    height: &dyn Fn(f64) -> f64,
+    jd: f64,
+    days: f64,
+    step: f64,
+) -> Option<f64> {
+    let crosses = |early: f64, late: f64| match event {
+        Horizon::Rise => early < 0.0 && late >= 0.0,
+        Horizon::Set => early >= 0.0 && late < 0.0,
+    };
+
+    let steps = (days / step.abs()).ceil() as usize;
+    let mut a = jd;
+    let mut ha = height(a);
+
+    for _ in 0..steps {
+        let b = a + step;
+        let hb = height(b);
+
+        // Order the interval in time
+        let (mut lo, mut hi, h_lo, h_hi) = if step > 0.0 {
+            (a, b, ha, hb)
+        } else {
+            (b, a, hb, ha)
+        };
+
+        if crosses(h_lo, h_hi) {
+            while hi - lo > PRECISION {
+                let mid = 0.5 * (lo + hi);
+                if crosses(h_lo, height(mid)) {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+            }
+            return Some(0.5 * (lo + hi));
+        }
+
+        a = b;
+        ha = hb;
+    }
+
+    None
+}
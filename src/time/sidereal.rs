@@ -1,5 +1,7 @@
 use std::f64::consts::TAU;
 
+use crate::frames::nutation::nutation;
+
 /// Greenwich Mean Sidereal Time (radians)
 ///
 /// Input:
@@ -29,6 +31,28 @@ pub fn local_sidereal_time(jd_ut: f64, longitude_rad: f64) -> f64 {
     normalize(greenwich_sidereal_time(jd_ut) + longitude_rad)
 }
 
+/// Greenwich Apparent Sidereal Time (radians)
+///
+/// GMST corrected by the equation of the equinoxes (Δψ·cos ε).
+///
+/// Input:
+/// - jd_ut : Julian Day (UT)
+/// - jd_tt : Julian Day (TT), for nutation
+pub fn greenwich_apparent_sidereal_time(jd_ut: f64, jd_tt: f64) -> f64 {
+    let n = nutation(jd_tt);
+    normalize(greenwich_sidereal_time(jd_ut) + n.delta_psi * n.eps_true.cos())
+}
+
+/// Local Apparent Sidereal Time (radians)
+///
+/// Input:
+/// - jd_ut         : Julian Day (UT)
+/// - jd_tt         : Julian Day (TT)
+/// - longitude_rad : observer longitude (east positive)
+pub fn local_apparent_sidereal_time(jd_ut: f64, jd_tt: f64, longitude_rad: f64) -> f64 {
+    normalize(greenwich_apparent_sidereal_time(jd_ut, jd_tt) + longitude_rad)
+}
+
 #[inline]
 fn normalize(mut a: f64) -> f64 {
     a %= TAU;
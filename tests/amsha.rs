@@ -0,0 +1,82 @@
+use kundli_core::divisional::{AmshaNature, amsha};
+
+fn index(division: u8, lon: f64) -> u16 {
+    amsha(division, lon).index
+}
+
+fn name(division: u8, lon: f64) -> &'static str {
+    amsha(division, lon).name.unwrap()
+}
+
+#[test]
+fn shashtiamsa_odd_sign_runs_forward() {
+    // Aries: Ghora first, Chandrarekha last
+    let first = amsha(60, 0.1);
+    assert_eq!(
+        (first.index, first.name, first.nature),
+        (1, Some("Ghora"), Some(AmshaNature::Malefic))
+    );
+
+    let third = amsha(60, 1.2);
+    assert_eq!(
+        (third.index, third.name, third.nature),
+        (3, Some("Deva"), Some(AmshaNature::Benefic))
+    );
+
+    let last = amsha(60, 29.9);
+    assert_eq!(
+        (last.index, last.name, last.nature),
+        (60, Some("Chandrarekha"), Some(AmshaNature::Benefic))
+    );
+}
+
+#[test]
+fn shashtiamsa_even_sign_runs_in_reverse() {
+    // Taurus: Chandrarekha first, Ghora last
+    assert_eq!((index(60, 30.1), name(60, 30.1)), (60, "Chandrarekha"));
+    assert_eq!((index(60, 30.6), name(60, 30.6)), (59, "Bhramana"));
+    assert_eq!((index(60, 59.9), name(60, 59.9)), (1, "Ghora"));
+}
+
+#[test]
+fn khavedamsa_lords_repeat_every_twelve() {
+    assert_eq!(name(40, 0.1), "Vishnu");
+    assert_eq!(name(40, 0.8), "Chandra");
+    // 13th amsha (9°–9°45′) starts the lords again
+    assert_eq!((index(40, 9.1), name(40, 9.1)), (13, "Vishnu"));
+    assert_eq!((index(40, 29.9), name(40, 29.9)), (40, "Tvashta"));
+
+    // No reversal in even signs
+    assert_eq!(name(40, 30.1), "Vishnu");
+    assert_eq!(amsha(40, 30.1).nature, None);
+}
+
+#[test]
+fn akshavedamsa_lords_by_modality() {
+    // Movable from Brahma, fixed from Shiva, dual from Vishnu
+    assert_eq!(name(45, 0.1), "Brahma");
+    assert_eq!(name(45, 0.7), "Shiva");
+    assert_eq!(name(45, 29.9), "Vishnu");
+    assert_eq!(name(45, 30.1), "Shiva");
+    assert_eq!(name(45, 60.1), "Vishnu");
+}
+
+#[test]
+fn nadi_amsha_order() {
+    // Movable signs count forward
+    assert_eq!(index(150, 0.1), 1);
+    assert_eq!(index(150, 0.3), 2);
+    assert_eq!(index(150, 29.9), 150);
+
+    // Fixed signs in reverse
+    assert_eq!(index(150, 30.1), 150);
+    assert_eq!(index(150, 59.9), 1);
+
+    // Dual signs from the 76th nadi, wrapping at 150
+    assert_eq!(index(150, 60.1), 76);
+    assert_eq!(index(150, 74.9), 150);
+    assert_eq!(index(150, 75.1), 1);
+    assert_eq!(index(150, 89.9), 75);
+
+    assert_eq!(amsha(150, 0.1).name, None);
+}
@@ -0,0 +1,85 @@
+use std::f64::consts::{PI, TAU};
+
+use kundli_core::astrology::angles::angles_tropical;
+use kundli_core::frames::nutation::nutation;
+use kundli_core::time::sidereal::local_apparent_sidereal_time;
+
+// 2024-03-15 06:00 UT and six hours later
+const TIMES: [f64; 2] = [2460384.75, 2460385.0];
+const DELTA_T: f64 = 69.0 / 86400.0;
+
+// (place, latitude, longitude) in degrees
+// Below 23.4° the Vertex comes from a co-latitude inside the
+// polar circle.
+const PLACES: [(&str, f64, f64); 6] = [
+    ("Bengaluru", 12.9716, 77.5946),
+    ("Jakarta", -6.2088, 106.8456),
+    ("New Delhi", 28.6139, 77.2090),
+    ("London", 51.5074, -0.1278),
+    ("Sydney", -33.8688, 151.2093),
+    ("Punta Arenas", -53.1638, -70.9171),
+];
+
+fn wrapped_diff(a: f64, b: f64) -> f64 {
+    ((a - b + PI).rem_euclid(TAU) - PI).abs()
+}
+
+/// Equatorial unit vector of an ecliptic longitude
+fn ecliptic_point(lon: f64, eps: f64) -> [f64; 3] {
+    [lon.cos(), lon.sin() * eps.cos(), lon.sin() * eps.sin()]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[test]
+fn mc_lies_on_the_upper_meridian() {
+    for jd_ut in TIMES {
+        let jd_tt = jd_ut + DELTA_T;
+        let eps = nutation(jd_tt).eps_true;
+
+        for (place, lat, lon) in PLACES {
+            let (lat, lon) = (lat.to_radians(), lon.to_radians());
+            let a = angles_tropical(jd_ut, jd_tt, lat, lon);
+            let ramc = local_apparent_sidereal_time(jd_ut, jd_tt, lon);
+
+            let v = ecliptic_point(a.mc, eps);
+            let ra = v[1].atan2(v[0]);
+
+            assert!(wrapped_diff(ra, ramc) < 1e-9, "{place}: MC off meridian");
+            assert!(wrapped_diff(a.ic, a.mc + PI) < 1e-12, "{place}: IC");
+        }
+    }
+}
+
+#[test]
+fn vertex_lies_on_the_western_prime_vertical() {
+    for jd_ut in TIMES {
+        let jd_tt = jd_ut + DELTA_T;
+        let eps = nutation(jd_tt).eps_true;
+
+        for (place, lat, lon) in PLACES {
+            let (lat, lon) = (lat.to_radians(), lon.to_radians());
+            let a = angles_tropical(jd_ut, jd_tt, lat, lon);
+            let ramc = local_apparent_sidereal_time(jd_ut, jd_tt, lon);
+
+            // The prime vertical's pole is the north point of the horizon
+            let north = [-lat.sin() * ramc.cos(), -lat.sin() * ramc.sin(), lat.cos()];
+            let east = [-ramc.sin(), ramc.cos(), 0.0];
+
+            let v = ecliptic_point(a.vertex, eps);
+
+            assert!(
+                dot(v, north).abs() < 1e-9,
+                "{place}: vertex {} off the prime vertical",
+                a.vertex.to_degrees()
+            );
+            assert!(dot(v, east) < 0.0, "{place}: vertex not in the west");
+            assert!(
+                wrapped_diff(a.anti_vertex, a.vertex + PI) < 1e-12,
+                "{place}: anti-vertex"
+            );
+        }
+    }
+}
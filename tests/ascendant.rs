@@ -0,0 +1,108 @@
+use std::f64::consts::{PI, TAU};
+
+use kundli_core::astrology::houses::{ascendant_from_lst, ascendant_sidereal};
+
+const OBLIQ: f64 = 0.409_092_804; // ~23.4393°
+
+fn wrapped_diff(a: f64, b: f64) -> f64 {
+    ((a - b + PI).rem_euclid(TAU) - PI).abs()
+}
+
+/// Altitude and hour angle of an ecliptic point (latitude 0)
+fn horizon_position(lon: f64, lst: f64, lat: f64) -> (f64, f64) {
+    let ra = (lon.sin() * OBLIQ.cos()).atan2(lon.cos());
+    let dec = (lon.sin() * OBLIQ.sin()).asin();
+    let ha = lst - ra;
+
+    let alt = (lat.sin() * dec.sin() + lat.cos() * dec.cos() * ha.cos()).asin();
+    (alt, ha.sin())
+}
+
+#[test]
+fn equator_matches_closed_form_at_cardinal_lst() {
+    // On the equator the ascendant has right ascension LST + 90°
+    for (lst_deg, expected_deg) in [(0.0, 90.0), (90.0, 180.0), (180.0, 270.0), (270.0, 0.0)] {
+        let asc = ascendant_from_lst(f64::to_radians(lst_deg), 0.0, OBLIQ);
+        assert!(
+            wrapped_diff(asc, f64::to_radians(expected_deg)) < 1e-12,
+            "LST {lst_deg}: got {}",
+            asc.to_degrees()
+        );
+    }
+}
+
+#[test]
+fn continuous_across_cardinal_lst() {
+    let eps = 1e-9;
+
+    for lat_deg in [-60.0, -23.0, 0.0, 28.6, 51.5, 65.0, 80.0] {
+        let lat = f64::to_radians(lat_deg);
+
+        for lst_deg in [0.0, 90.0, 180.0, 270.0] {
+            let lst = f64::to_radians(lst_deg);
+            let before = ascendant_from_lst(lst - eps, lat, OBLIQ);
+            let at = ascendant_from_lst(lst, lat, OBLIQ);
+            let after = ascendant_from_lst(lst + eps, lat, OBLIQ);
+
+            assert!(at.is_finite() && (0.0..TAU).contains(&at));
+            assert!(
+                wrapped_diff(before, at) < 1e-6,
+                "lat {lat_deg} LST {lst_deg}"
+            );
+            assert!(
+                wrapped_diff(after, at) < 1e-6,
+                "lat {lat_deg} LST {lst_deg}"
+            );
+        }
+    }
+}
+
+#[test]
+fn ascendant_lies_on_eastern_horizon() {
+    // Includes latitudes inside the polar circles, where parts of
+    // the ecliptic never rise
+    for lat_deg in [-75.0, -45.0, 0.0, 28.6, 51.5, 60.0, 65.0, 70.0, 80.0] {
+        let lat = f64::to_radians(lat_deg);
+
+        for lst_deg in (0..360).map(f64::from) {
+            let lst = f64::to_radians(lst_deg);
+            let asc = ascendant_from_lst(lst, lat, OBLIQ);
+            let (alt, sin_ha) = horizon_position(asc, lst, lat);
+
+            assert!(alt.abs() < 1e-9, "lat {lat_deg} LST {lst_deg}: alt {alt}");
+            assert!(sin_ha < 0.0, "lat {lat_deg} LST {lst_deg}: not east");
+        }
+    }
+}
+
+#[test]
+fn finite_at_poles() {
+    for lat_deg in [-90.0, 90.0, 89.999_999] {
+        for lst_deg in [0.0, 90.0, 180.0, 270.0] {
+            let asc = ascendant_from_lst(f64::to_radians(lst_deg), f64::to_radians(lat_deg), OBLIQ);
+            assert!(asc.is_finite() && (0.0..TAU).contains(&asc));
+        }
+    }
+}
+
+#[test]
+fn sidereal_ascendant_regression() {
+    // 2000-01-01 12:00 UT, New Delhi (28.6139 N, 77.2090 E)
+    let jd_ut = 2451545.0;
+    let jd_tt = jd_ut + 63.83 / 86400.0;
+
+    let asc = ascendant_sidereal(
+        jd_ut,
+        jd_tt,
+        28.6139_f64.to_radians(),
+        77.2090_f64.to_radians(),
+    );
+
+    assert!(asc.is_finite() && (0.0..TAU).contains(&asc));
+    // Sidereal Cancer lagna (tropical ≈ 100°, minus ≈ 23.86° Lahiri)
+    assert!(
+        (asc.to_degrees() - 76.3).abs() < 0.5,
+        "got {}",
+        asc.to_degrees()
+    );
+}
@@ -0,0 +1,134 @@
+use kundli_core::PlanetJS;
+use kundli_core::dasha::ashtottari::{
+    ASHTOTTARI, ashtottari_applicability, ashtottari_mahadasha_timeline,
+};
+use kundli_core::dasha::nakshatra_dasha::birth_balance;
+use kundli_core::dasha::vimshottari::{DashaLord, SIDEREAL_YEAR, VIMSHOTTARI};
+
+use DashaLord::*;
+
+const NAK_LEN: f64 = 360.0 / 27.0;
+
+fn planet(name: &str, sidereal_deg: f64) -> PlanetJS {
+    PlanetJS {
+        name: name.to_string(),
+        tropical_deg: sidereal_deg,
+        sidereal_deg,
+    }
+}
+
+#[test]
+fn groups_start_from_ardra() {
+    // (lord, nakshatras from 0 = Ashwini)
+    let groups: [(DashaLord, &[usize]); 8] = [
+        (Sun, &[5, 6, 7, 8]),
+        (Moon, &[9, 10, 11]),
+        (Mars, &[12, 13, 14, 15]),
+        (Mercury, &[16, 17, 18]),
+        (Saturn, &[19, 20, 21]),
+        (Jupiter, &[22, 23, 24]),
+        (Rahu, &[25, 26, 0, 1]),
+        (Venus, &[2, 3, 4]),
+    ];
+
+    for (lord, naks) in groups {
+        for (pos, &nak) in naks.iter().enumerate() {
+            assert_eq!(
+                ASHTOTTARI.lord_of(nak),
+                (lord, pos, naks.len()),
+                "nakshatra {nak}"
+            );
+        }
+    }
+}
+
+#[test]
+fn cycle_is_108_years() {
+    let total: f64 = ASHTOTTARI.lords.iter().map(|l| l.1).sum();
+    assert_eq!(total, 108.0);
+    assert_eq!(ASHTOTTARI.cycle_years, 108.0);
+    assert_eq!(ASHTOTTARI.years(Ketu), None);
+
+    let timeline = ashtottari_mahadasha_timeline(2451545.0, 100.0);
+    assert_eq!(timeline.len(), 8);
+
+    let days = timeline[7].end_jd - timeline[0].start_jd;
+    assert!((days - 108.0 * SIDEREAL_YEAR).abs() < 1e-6);
+}
+
+#[test]
+fn ketu_chains_are_rejected() {
+    // Ketu has no Ashtottari years, and `next` would keep it
+    assert_eq!(ASHTOTTARI.check_chain(&[Saturn, Ketu]), Err(Ketu));
+    assert_eq!(ASHTOTTARI.check_chain(&[Saturn, Rahu]), Ok(()));
+    assert_eq!(VIMSHOTTARI.check_chain(&[Saturn, Ketu]), Ok(()));
+}
+
+#[test]
+fn balance_runs_over_the_whole_group() {
+    // Start of Pushya: half of the Sun's Ardra..Ashlesha group gone
+    let (lord, years) = birth_balance(&ASHTOTTARI, 7.0 * NAK_LEN + 1e-9);
+    assert_eq!(lord, Sun);
+    assert!((years - 3.0).abs() < 1e-6);
+
+    // Middle of Ardra: an eighth gone
+    let (lord, years) = birth_balance(&ASHTOTTARI, 5.5 * NAK_LEN);
+    assert_eq!(lord, Sun);
+    assert!((years - 5.25).abs() < 1e-9);
+
+    // Middle of Bharani, last of Rahu's four nakshatras
+    let (lord, years) = birth_balance(&ASHTOTTARI, 1.5 * NAK_LEN);
+    assert_eq!(lord, Rahu);
+    assert!((years - 12.0 / 8.0).abs() < 1e-9);
+}
+
+/// Aries lagna with Mars (lagna lord) in Cancer
+fn chart(sun: f64, moon: f64, rahu: f64) -> Vec<PlanetJS> {
+    vec![
+        planet("Sun", sun),
+        planet("Moon", moon),
+        planet("Mars", 100.0),
+        planet("Rahu", rahu),
+    ]
+}
+
+#[test]
+fn rahu_from_lagna_lord() {
+    let asc = 10.0;
+    // Night birth in Krishna paksha fails the paksha condition
+    let (sun, moon) = (100.0, 300.0);
+
+    // Libra: 4th from Mars
+    let a = ashtottari_applicability(asc, &chart(sun, moon, 190.0)).unwrap();
+    assert!(a.rahu_from_lagna_lord && !a.paksha_day_night && a.applies());
+
+    // Aries: 10th from Mars but in the lagna itself
+    let a = ashtottari_applicability(asc, &chart(sun, moon, 10.0)).unwrap();
+    assert!(!a.rahu_from_lagna_lord && !a.applies());
+
+    // Taurus: 11th from Mars
+    let a = ashtottari_applicability(asc, &chart(sun, moon, 40.0)).unwrap();
+    assert!(!a.rahu_from_lagna_lord);
+}
+
+#[test]
+fn paksha_and_day_night() {
+    let asc = 10.0;
+    let rahu = 40.0;
+
+    // Sun at 280° is above the horizon of a 10° lagna: day birth
+    // Krishna paksha by day
+    let a = ashtottari_applicability(asc, &chart(280.0, 120.0, rahu)).unwrap();
+    assert!(a.paksha_day_night && a.applies());
+
+    // Shukla paksha by day
+    let a = ashtottari_applicability(asc, &chart(280.0, 300.0, rahu)).unwrap();
+    assert!(!a.paksha_day_night && !a.applies());
+
+    // Shukla paksha by night
+    let a = ashtottari_applicability(asc, &chart(100.0, 150.0, rahu)).unwrap();
+    assert!(a.paksha_day_night);
+
+    // Rahu is required
+    assert!(ashtottari_applicability(asc, &[planet("Sun", 1.0), planet("Moon", 2.0)]).is_none());
+}
@@ -0,0 +1,119 @@
+use kundli_core::PlanetJS;
+use kundli_core::astrology::bhava::{bhava_chalit, bhava_of, sripati_bhavas};
+
+const ASC: f64 = 120.0;
+const MC: f64 = 15.0;
+
+fn close(a: f64, b: f64) -> bool {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d) < 1e-9
+}
+
+fn planet(name: &str, sidereal_deg: f64) -> PlanetJS {
+    PlanetJS {
+        name: name.to_string(),
+        tropical_deg: sidereal_deg,
+        sidereal_deg,
+    }
+}
+
+#[test]
+fn sripati_madhya_trisects_each_quadrant() {
+    // MC → Asc spans 105° (35° per bhava), Asc → IC spans 75° (25°)
+    let expected = [
+        120.0, 145.0, 170.0, 195.0, 230.0, 265.0, 300.0, 325.0, 350.0, 15.0, 50.0, 85.0,
+    ];
+
+    let bhavas = sripati_bhavas(ASC.to_radians(), MC.to_radians());
+
+    for (b, madhya) in bhavas.iter().zip(expected) {
+        assert!(
+            close(b.madhya.to_degrees(), madhya),
+            "bhava {}: madhya {}",
+            b.number,
+            b.madhya.to_degrees()
+        );
+    }
+}
+
+#[test]
+fn sandhis_are_midpoints_between_madhyas() {
+    let bhavas = sripati_bhavas(ASC.to_radians(), MC.to_radians());
+
+    // (bhava, start sandhi, end sandhi)
+    for (number, start, end) in [
+        (1, 102.5, 132.5),
+        (4, 182.5, 212.5),
+        (7, 282.5, 312.5),
+        // Wraps through 0° Aries
+        (10, 2.5, 32.5),
+    ] {
+        let b = &bhavas[number - 1];
+        assert!(
+            close(b.start_sandhi.to_degrees(), start),
+            "bhava {number} start"
+        );
+        assert!(close(b.end_sandhi.to_degrees(), end), "bhava {number} end");
+    }
+
+    for (i, b) in bhavas.iter().enumerate() {
+        let next = &bhavas[(i + 1) % 12];
+        assert!(close(
+            b.end_sandhi.to_degrees(),
+            next.start_sandhi.to_degrees()
+        ));
+    }
+}
+
+#[test]
+fn bhava_of_follows_the_sandhis() {
+    let bhavas = sripati_bhavas(ASC.to_radians(), MC.to_radians());
+
+    for (lon, bhava) in [
+        (102.5, 1),
+        (132.4, 1),
+        (132.6, 2),
+        (1.0, 9),
+        (3.0, 10),
+        (359.0, 9),
+    ] {
+        assert_eq!(bhava_of(f64::to_radians(lon), &bhavas), bhava, "{lon}°");
+    }
+}
+
+#[test]
+fn planets_near_a_sandhi_shift_house() {
+    let bhavas = sripati_bhavas(ASC.to_radians(), MC.to_radians());
+
+    // Leo lagna: Cancer is the 12th, Leo the 1st, Aries the 9th
+    let planets = [
+        planet("Sun", 132.0),
+        planet("Moon", 133.0),
+        planet("Mars", 101.0),
+        planet("Venus", 103.0),
+        planet("Saturn", 1.0),
+        planet("Jupiter", 3.0),
+    ];
+
+    let placements = bhava_chalit(ASC, &planets, &bhavas);
+
+    // (rashi house, bhava, shifted)
+    let expected = [
+        (1, 1, false),
+        (1, 2, true),
+        (12, 12, false),
+        (12, 1, true),
+        (9, 9, false),
+        (9, 10, true),
+    ];
+
+    for (p, (house, bhava, shifted)) in placements.iter().zip(expected) {
+        assert_eq!(
+            (p.rashi_house, p.bhava, p.shifted),
+            (house, bhava, shifted),
+            "{}",
+            p.planet
+        );
+    }
+    assert_eq!(placements.len(), planets.len());
+}
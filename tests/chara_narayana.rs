@@ -0,0 +1,199 @@
+use kundli_core::PlanetJS;
+use kundli_core::dasha::chara::{CharaVariant, chara_dasha, chara_dasha_years};
+use kundli_core::dasha::narayana::{narayana_dasha, narayana_sequence, narayana_start};
+use kundli_core::dasha::rashi::RashiChart;
+use kundli_core::dasha::year::{DashaClock, DashaYear};
+
+fn planet(name: &str, sidereal_deg: f64) -> PlanetJS {
+    PlanetJS {
+        name: name.to_string(),
+        tropical_deg: sidereal_deg,
+        sidereal_deg,
+    }
+}
+
+fn rashi_chart(asc: f64, planets: &[(&str, f64)]) -> RashiChart {
+    let planets: Vec<PlanetJS> = planets.iter().map(|&(n, l)| planet(n, l)).collect();
+    RashiChart::new(asc, &planets)
+}
+
+/// Aries lagna worked example
+///
+/// Mars in Capricorn, Jupiter in Cancer, Venus in Pisces and
+/// Mercury in Virgo exalted; Saturn debilitated in Aries; Moon,
+/// Sun and Mercury in their own signs; Ketu in Scorpio.
+fn example() -> RashiChart {
+    rashi_chart(
+        5.0,
+        &[
+            ("Sun", 130.0),     // Leo
+            ("Moon", 95.0),     // Cancer
+            ("Mars", 290.0),    // Capricorn
+            ("Mercury", 160.0), // Virgo
+            ("Jupiter", 102.0), // Cancer
+            ("Venus", 340.0),   // Pisces
+            ("Saturn", 15.0),   // Aries
+            ("Rahu", 50.0),     // Taurus
+            ("Ketu", 230.0),    // Scorpio
+        ],
+    )
+}
+
+fn clock() -> DashaClock {
+    DashaClock::new(2451545.0, DashaYear::Julian)
+}
+
+#[test]
+fn chara_years_kn_rao() {
+    let chart = example();
+
+    // Aries .. Pisces, counted by footedness, no dignity change.
+    // Scorpio uses Mars (Ketu sits in Scorpio); Aquarius uses
+    // Rahu (same occupants, further advanced than Saturn).
+    let expected = [
+        9.0, 10.0, 3.0, 12.0, 12.0, 12.0, 5.0, 2.0, 7.0, 9.0, 9.0, 8.0,
+    ];
+
+    for (sign, years) in expected.into_iter().enumerate() {
+        assert_eq!(
+            chara_dasha_years(&chart, sign as u8, CharaVariant::KnRao),
+            years,
+            "sign {sign}"
+        );
+    }
+}
+
+#[test]
+fn chara_years_sanjay_rath() {
+    let chart = example();
+
+    // Counted by odd/even sign, +1 exalted lord, −1 debilitated
+    let expected = [
+        10.0, 3.0, 4.0, 12.0, 12.0, 13.0, 6.0, 11.0, 8.0, 8.0, 3.0, 9.0,
+    ];
+
+    for (sign, years) in expected.into_iter().enumerate() {
+        assert_eq!(
+            chara_dasha_years(&chart, sign as u8, CharaVariant::SanjayRath),
+            years,
+            "sign {sign}"
+        );
+    }
+}
+
+#[test]
+fn chara_dasha_runs_two_cycles() {
+    let chart = example();
+    let dashas = chara_dasha(&clock(), &chart, CharaVariant::KnRao);
+
+    // 9th from Aries is Sagittarius (savya): zodiacal order
+    let first: Vec<u8> = dashas[..12].iter().map(|d| d.sign).collect();
+    assert_eq!(first, (0..12).collect::<Vec<u8>>());
+
+    // Second cycle 12 − n; Cancer, Leo and Virgo (12 years) drop out
+    let second: Vec<(u8, f64)> = dashas[12..].iter().map(|d| (d.sign, d.years)).collect();
+    assert_eq!(
+        second,
+        [
+            (0, 3.0),
+            (1, 2.0),
+            (2, 9.0),
+            (6, 7.0),
+            (7, 10.0),
+            (8, 5.0),
+            (9, 3.0),
+            (10, 3.0),
+            (11, 4.0)
+        ]
+    );
+
+    assert_eq!(dashas[0].start_jd, 2451545.0);
+    assert!((dashas[0].end_jd - (2451545.0 + 9.0 * 365.25)).abs() < 1e-6);
+}
+
+#[test]
+fn narayana_movable_sequences() {
+    let empty = rashi_chart(5.0, &[]);
+
+    // Odd movable (Aries) forward, even movable (Cancer) backward
+    assert_eq!(
+        narayana_sequence(&empty, 0),
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]
+    );
+    assert_eq!(
+        narayana_sequence(&empty, 3),
+        [3, 2, 1, 0, 11, 10, 9, 8, 7, 6, 5, 4]
+    );
+}
+
+#[test]
+fn narayana_fixed_sequences() {
+    let empty = rashi_chart(5.0, &[]);
+
+    // Every 6th sign: Leo forward, Taurus backward
+    assert_eq!(
+        narayana_sequence(&empty, 4),
+        [4, 9, 2, 7, 0, 5, 10, 3, 8, 1, 6, 11]
+    );
+    assert_eq!(
+        narayana_sequence(&empty, 1),
+        [1, 8, 3, 10, 5, 0, 7, 2, 9, 4, 11, 6]
+    );
+}
+
+#[test]
+fn narayana_dual_sequences() {
+    let empty = rashi_chart(5.0, &[]);
+
+    // Kendras from the sign, then from the next: Gemini forward,
+    // Virgo backward
+    assert_eq!(
+        narayana_sequence(&empty, 2),
+        [2, 5, 8, 11, 3, 6, 9, 0, 4, 7, 10, 1]
+    );
+    assert_eq!(
+        narayana_sequence(&empty, 5),
+        [5, 2, 11, 8, 4, 1, 10, 7, 3, 0, 9, 6]
+    );
+}
+
+#[test]
+fn saturn_and_ketu_change_the_direction() {
+    // Saturn in even Cancer: zodiacal
+    let saturn = rashi_chart(5.0, &[("Saturn", 100.0)]);
+    assert_eq!(narayana_sequence(&saturn, 3)[..3], [3, 4, 5]);
+
+    // Ketu in odd Aries: reversed
+    let ketu = rashi_chart(5.0, &[("Ketu", 10.0)]);
+    assert_eq!(narayana_sequence(&ketu, 0)[..3], [0, 11, 10]);
+}
+
+#[test]
+fn narayana_dasha_of_the_example() {
+    let chart = example();
+
+    // Aries holds Saturn, Libra is empty
+    assert_eq!(narayana_start(&chart), 0);
+
+    let dashas = narayana_dasha(&clock(), &chart);
+    let years: Vec<(u8, f64)> = dashas[..12].iter().map(|d| (d.sign, d.years)).collect();
+
+    // By footedness, ±1 for the lord's dignity
+    assert_eq!(
+        years,
+        [
+            (0, 10.0),
+            (1, 11.0),
+            (2, 4.0),
+            (3, 12.0),
+            (4, 12.0),
+            (5, 13.0),
+            (6, 6.0),
+            (7, 3.0),
+            (8, 8.0),
+            (9, 8.0),
+            (10, 9.0),
+            (11, 9.0)
+        ]
+    );
+}
@@ -0,0 +1,21 @@
+// Shared by the dasha integration tests; not every test uses
+// every item.
+#![allow(dead_code)]
+
+use kundli_core::time::datetime::{DateTimeInput, jd_tt_from_datetime};
+
+/// Seed longitude of the dasha tests (sidereal, in Ashlesha)
+pub const SEED: f64 = 123.456;
+
+/// JD (TT) of 0h UTC on a date
+pub fn date(year: i32, month: u8, day: u8) -> f64 {
+    jd_tt_from_datetime(DateTimeInput {
+        year,
+        month,
+        day,
+        hour: 0,
+        minute: 0,
+        second: 0.0,
+        tz_offset_hours: 0.0,
+    })
+}
@@ -0,0 +1,113 @@
+use kundli_core::PlanetJS;
+use kundli_core::dasha::conditional::ConditionalDasha;
+use kundli_core::dasha::vimshottari::DashaLord;
+
+use ConditionalDasha::*;
+use DashaLord::*;
+
+fn planet(name: &str, sidereal_deg: f64) -> PlanetJS {
+    PlanetJS {
+        name: name.to_string(),
+        tropical_deg: sidereal_deg,
+        sidereal_deg,
+    }
+}
+
+fn planets(list: &[(&str, f64)]) -> Vec<PlanetJS> {
+    list.iter().map(|&(n, l)| planet(n, l)).collect()
+}
+
+#[test]
+fn year_totals() {
+    for (dasha, years) in [
+        (Shodashottari, 116.0),
+        (Dwadashottari, 112.0),
+        (Panchottari, 105.0),
+        (Shatabdika, 100.0),
+        (ChaturashitiSama, 84.0),
+        (DwisaptatiSama, 72.0),
+        (Shashtihayani, 60.0),
+    ] {
+        let system = dasha.system();
+        let total: f64 = system.lords.iter().map(|l| l.1).sum();
+
+        assert_eq!(total, years, "{}", dasha.name());
+        assert_eq!(system.cycle_years, years, "{}", dasha.name());
+    }
+}
+
+#[test]
+fn counts_start_from_their_nakshatra() {
+    // (system, first nakshatra, its lord, next nakshatra, its lord)
+    for (dasha, first, lord, next, next_lord) in [
+        (Shodashottari, 7, Sun, 8, Mars),      // Pushya
+        (Dwadashottari, 26, Sun, 25, Jupiter), // Revati, counted back
+        (Panchottari, 16, Sun, 17, Mercury),   // Anuradha
+        (Shatabdika, 26, Sun, 0, Moon),        // Revati
+        (ChaturashitiSama, 14, Sun, 15, Moon), // Swati
+        (DwisaptatiSama, 18, Sun, 19, Moon),   // Mula
+        (Shashtihayani, 0, Jupiter, 3, Sun),   // Ashwini, then Rohini
+    ] {
+        let system = dasha.system();
+        assert_eq!(system.lord_of(first).0, lord, "{}", dasha.name());
+        assert_eq!(system.lord_of(next).0, next_lord, "{}", dasha.name());
+    }
+
+    // Shashtihayani runs: Saturn holds only Shravana and Dhanishta
+    let system = Shashtihayani.system();
+    assert_eq!(system.lord_of(21), (Saturn, 0, 2));
+    assert_eq!(system.lord_of(22), (Saturn, 1, 2));
+    assert_eq!(system.lord_of(23), (Rahu, 0, 4));
+    assert_eq!(system.lord_of(26), (Rahu, 3, 4));
+}
+
+#[test]
+fn shodashottari_condition() {
+    // 5° Aries: Sun's hora
+    let krishna = planets(&[("Sun", 0.0), ("Moon", 200.0)]);
+    let shukla = planets(&[("Sun", 0.0), ("Moon", 100.0)]);
+
+    assert_eq!(Shodashottari.applies(5.0, &krishna), Some(true));
+    assert_eq!(Shodashottari.applies(5.0, &shukla), Some(false));
+    // 20° Aries: Moon's hora
+    assert_eq!(Shodashottari.applies(20.0, &shukla), Some(true));
+    assert_eq!(Shodashottari.applies(5.0, &[]), None);
+}
+
+#[test]
+fn lagna_conditions() {
+    // Navamsa lagna Taurus (5° Aries) or Aries (1° Aries)
+    assert_eq!(Dwadashottari.applies(5.0, &[]), Some(true));
+    assert_eq!(Dwadashottari.applies(1.0, &[]), Some(false));
+
+    // Cancer lagna: first dwadashamsa is Cancer, the second Leo
+    assert_eq!(Panchottari.applies(91.0, &[]), Some(true));
+    assert_eq!(Panchottari.applies(94.0, &[]), Some(false));
+
+    // Vargottama in the first navamsa of Aries only
+    assert_eq!(Shatabdika.applies(1.0, &[]), Some(true));
+    assert_eq!(Shatabdika.applies(5.0, &[]), Some(false));
+}
+
+#[test]
+fn house_conditions() {
+    // Aries lagna: Saturn rules the 10th
+    let pass = planets(&[("Saturn", 280.0)]);
+    let fail = planets(&[("Saturn", 10.0)]);
+    assert_eq!(ChaturashitiSama.applies(5.0, &pass), Some(true));
+    assert_eq!(ChaturashitiSama.applies(5.0, &fail), Some(false));
+    assert_eq!(ChaturashitiSama.applies(5.0, &[]), None);
+
+    // Mars (lagna lord) in the 7th, or Venus (7th lord) in the lagna
+    let mars = planets(&[("Mars", 190.0), ("Venus", 100.0)]);
+    let venus = planets(&[("Mars", 100.0), ("Venus", 20.0)]);
+    let neither = planets(&[("Mars", 100.0), ("Venus", 100.0)]);
+    assert_eq!(DwisaptatiSama.applies(5.0, &mars), Some(true));
+    assert_eq!(DwisaptatiSama.applies(5.0, &venus), Some(true));
+    assert_eq!(DwisaptatiSama.applies(5.0, &neither), Some(false));
+
+    let sun_in_lagna = planets(&[("Sun", 25.0)]);
+    let sun_in_2nd = planets(&[("Sun", 35.0)]);
+    assert_eq!(Shashtihayani.applies(5.0, &sun_in_lagna), Some(true));
+    assert_eq!(Shashtihayani.applies(5.0, &sun_in_2nd), Some(false));
+}
@@ -0,0 +1,70 @@
+use kundli_core::divisional::{
+    VargaScheme, VargaTableError, custom_scheme, register_varga_table, varga_position,
+};
+use kundli_core::register_varga_table_js;
+
+/// D3 table: amsha `a` of sign `s` goes to sign s + 4a + 1
+fn shifted_drekkana() -> Vec<u8> {
+    (0..12u8)
+        .flat_map(|sign| (0..3u8).map(move |a| (sign + 4 * a + 1) % 12))
+        .collect()
+}
+
+#[test]
+fn registered_table_is_found_by_name() {
+    let scheme = register_varga_table("ShiftedDrekkana", 3, shifted_drekkana()).unwrap();
+    assert!(matches!(scheme, VargaScheme::Custom(_)));
+
+    assert_eq!(custom_scheme("ShiftedDrekkana"), Some(scheme));
+    assert_eq!(VargaScheme::from_name("ShiftedDrekkana"), Some(scheme));
+    assert_eq!(custom_scheme("NotRegistered"), None);
+
+    // 12° Aries is the 2nd drekkana: 0 + 4 + 1 = Virgo
+    assert_eq!(varga_position(3, 12.0, scheme), (5, 2.0));
+    // 25° Scorpio is the 3rd: 7 + 8 + 1 = Leo
+    assert_eq!(varga_position(3, 235.0, scheme).0, 4);
+
+    // Other divisions fall back to Parashari
+    assert_eq!(
+        varga_position(9, 12.0, scheme),
+        varga_position(9, 12.0, VargaScheme::Parashari)
+    );
+}
+
+#[test]
+fn registering_a_name_again_replaces_the_table() {
+    let first = register_varga_table("ReplacedHora", 2, vec![0; 24]).unwrap();
+    assert_eq!(varga_position(2, 40.0, first).0, 0);
+
+    let second = register_varga_table("ReplacedHora", 2, vec![6; 24]).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(varga_position(2, 40.0, first).0, 6);
+}
+
+#[test]
+fn invalid_tables_are_rejected() {
+    assert_eq!(
+        register_varga_table("Bad", 61, vec![0; 12 * 61]),
+        Err(VargaTableError::UnsupportedDivision)
+    );
+    assert_eq!(
+        register_varga_table("Bad", 3, vec![0; 35]),
+        Err(VargaTableError::WrongLength)
+    );
+    assert_eq!(
+        register_varga_table("Bad", 2, vec![12; 24]),
+        Err(VargaTableError::InvalidSign)
+    );
+    assert_eq!(custom_scheme("Bad"), None);
+}
+
+#[test]
+fn wasm_registration_is_usable_by_name() {
+    // Hora with both halves in the sign itself
+    let signs: Vec<u8> = (0..12u8).flat_map(|s| [s, s]).collect();
+    assert!(register_varga_table_js("SelfHora", 2, signs).is_ok());
+
+    let scheme = VargaScheme::from_name("SelfHora").unwrap();
+    assert_eq!(varga_position(2, 200.0, scheme).0, 6);
+    assert_eq!(varga_position(2, 215.0, scheme).0, 7);
+}
@@ -0,0 +1,247 @@
+use kundli_core::compat::{Compatibility, MoonLongitude, NutationConvention};
+use kundli_core::dasha::nakshatra_dasha::{birth_balance, nakshatra_mahadasha_timeline};
+use kundli_core::dasha::vimshottari::{DashaLord, VIMSHOTTARI};
+use kundli_core::dasha::year::{DashaClock, DashaYear};
+use kundli_core::frames::ayanamsa::Ayanamsa;
+use kundli_core::frames::nutation::nutation;
+use kundli_core::time::calendar::jd_to_calendar;
+use kundli_core::time::datetime::{DateTimeInput, jd_tt_from_datetime};
+
+use DashaLord::*;
+
+/// A birth with the Vimshottari start recorded from this crate
+/// under each preset: (lord at birth, end date of that
+/// mahadasha).
+///
+/// These are regression values, not output of Jagannatha Hora or
+/// KP software; they only catch unintended changes. `note` says
+/// what, if anything, was checked elsewhere.
+struct Reference {
+    name: &'static str,
+    note: &'static str,
+    birth: DateTimeInput,
+    astronomical: (DashaLord, &'static str),
+    jhora: (DashaLord, &'static str),
+    kp: (DashaLord, &'static str),
+}
+
+fn birth(year: i32, month: u8, day: u8, hour: u8, minute: u8, tz: f64) -> DateTimeInput {
+    DateTimeInput {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second: 0.0,
+        tz_offset_hours: tz,
+    }
+}
+
+fn references() -> Vec<Reference> {
+    vec![
+        Reference {
+            name: "India independence, Delhi",
+            note: "Moon in Pushya and Saturn dasha as in published charts",
+            birth: birth(1947, 8, 15, 0, 0, 5.5),
+            astronomical: (Saturn, "1956-01-07"),
+            jhora: (Saturn, "1956-01-13"),
+            kp: (Saturn, "1955-11-23"),
+        },
+        Reference {
+            name: "Porbandar, local mean time",
+            note: "recorded",
+            birth: birth(1869, 10, 2, 7, 12, 69.6293 / 15.0),
+            astronomical: (Ketu, "1870-08-31"),
+            jhora: (Ketu, "1870-09-02"),
+            kp: (Ketu, "1870-08-15"),
+        },
+        Reference {
+            name: "Equinox morning, IST",
+            note: "recorded",
+            birth: birth(1984, 3, 21, 6, 30, 5.5),
+            astronomical: (Jupiter, "1996-09-27"),
+            jhora: (Jupiter, "1996-10-02"),
+            kp: (Jupiter, "1996-08-21"),
+        },
+        Reference {
+            name: "J2000 noon, UTC",
+            note: "recorded",
+            birth: birth(2000, 1, 1, 12, 0, 0.0),
+            astronomical: (Jupiter, "2015-08-14"),
+            jhora: (Jupiter, "2015-08-19"),
+            kp: (Jupiter, "2015-07-07"),
+        },
+        Reference {
+            name: "Western hemisphere evening",
+            note: "recorded",
+            birth: birth(1975, 6, 10, 18, 45, -4.0),
+            astronomical: (Rahu, "1986-04-03"),
+            jhora: (Rahu, "1986-04-04"),
+            kp: (Rahu, "1986-02-15"),
+        },
+        Reference {
+            name: "Year end, CET",
+            note: "recorded",
+            birth: birth(2021, 12, 31, 23, 59, 1.0),
+            astronomical: (Mercury, "2033-02-24"),
+            jhora: (Mercury, "2033-03-01"),
+            kp: (Mercury, "2033-01-15"),
+        },
+    ]
+}
+
+/// Lord running at birth and the end date of its mahadasha
+fn dasha_start(input: DateTimeInput, compat: &Compatibility) -> (DashaLord, String) {
+    let jd_tt = jd_tt_from_datetime(input);
+    let moon = compat.moon_sidereal_deg(jd_tt);
+    let clock = DashaClock::new(jd_tt, compat.year);
+
+    let first = &nakshatra_mahadasha_timeline(&VIMSHOTTARI, &clock, moon)[0];
+    let end = jd_to_calendar(first.end_jd);
+
+    (
+        first.lord,
+        format!("{:04}-{:02}-{:02}", end.year, end.month, end.day),
+    )
+}
+
+#[test]
+fn dasha_starts_stay_as_recorded() {
+    for r in references() {
+        for (preset, compat, expected) in [
+            ("Astronomical", Compatibility::ASTRONOMICAL, r.astronomical),
+            ("JHora", Compatibility::JHORA, r.jhora),
+            ("KP", Compatibility::KP, r.kp),
+        ] {
+            let (lord, end) = dasha_start(r.birth, &compat);
+            assert_eq!(
+                (lord, end.as_str()),
+                expected,
+                "{} ({}) with {preset}",
+                r.name,
+                r.note
+            );
+        }
+    }
+}
+
+#[test]
+fn presets_differ_by_their_settings_only() {
+    let jd_tt = jd_tt_from_datetime(birth(1984, 3, 21, 6, 30, 5.5));
+
+    let base = Compatibility::ASTRONOMICAL;
+    let apparent = Compatibility {
+        nutation: NutationConvention::Apparent,
+        ..base
+    };
+    let iae = Compatibility {
+        ayanamsa: Ayanamsa::LahiriIae,
+        ..base
+    };
+
+    let moon = |c: &Compatibility| c.moon_sidereal_deg(jd_tt);
+
+    // Apparent longitudes add the nutation in longitude
+    let dpsi = nutation(jd_tt).delta_psi.to_degrees();
+    assert!((moon(&apparent) - moon(&base) - dpsi).abs() < 1e-9);
+
+    // Ayanamsa variants differ by their J2000 offsets
+    let offset = (85885.53 - 85860.0) / 3600.0;
+    assert!((moon(&base) - moon(&iae) - offset).abs() < 1e-9);
+
+    // JHora = both changes together
+    assert!((moon(&Compatibility::JHORA) - moon(&base) - dpsi + offset).abs() < 1e-9);
+}
+
+#[test]
+fn mean_moon_stays_near_true_moon() {
+    let mean = Compatibility {
+        moon: MoonLongitude::Mean,
+        ..Compatibility::ASTRONOMICAL
+    };
+
+    for r in references() {
+        let jd_tt = jd_tt_from_datetime(r.birth);
+        let d =
+            mean.moon_sidereal_deg(jd_tt) - Compatibility::ASTRONOMICAL.moon_sidereal_deg(jd_tt);
+        let d = (d + 540.0) % 360.0 - 180.0;
+
+        // Equation of centre, evection and variation add up to
+        // less than 8.5°
+        assert!(d.abs() < 8.5, "{}: {d}", r.name);
+    }
+}
+
+#[test]
+fn nakshatra_uses_the_longitude_as_given() {
+    // No hidden shift: a Moon at 0° starts the full Ketu dasha
+    assert_eq!(birth_balance(&VIMSHOTTARI, 0.0), (Ketu, 7.0));
+
+    // Half way through Bharani: half of Venus left
+    let (lord, years) = birth_balance(&VIMSHOTTARI, 13.333_333_333_333_334 * 1.5);
+    assert_eq!(lord, Venus);
+    assert!((years - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn year_length_scales_mahadashas() {
+    let jd_tt = 2451545.0;
+
+    for year in [
+        DashaYear::Savana,
+        DashaYear::Julian,
+        DashaYear::Tropical,
+        DashaYear::Gregorian,
+        DashaYear::Sidereal,
+    ] {
+        let clock = DashaClock::new(jd_tt, year);
+        let maha = nakshatra_mahadasha_timeline(&VIMSHOTTARI, &clock, 0.0);
+
+        // Ketu from its very start: 7 years of this length
+        assert_eq!(maha[0].lord, Ketu);
+        assert!((maha[0].end_jd - maha[0].start_jd - 7.0 * year.days()).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn solar_return_years_end_on_the_natal_sun() {
+    let jd_tt = jd_tt_from_datetime(birth(1984, 3, 21, 6, 30, 5.5));
+    let clock = DashaClock::new(jd_tt, DashaYear::SolarReturn);
+
+    for years in [1.0, 7.0, 20.0, 120.0] {
+        let jd = clock.jd(years);
+        assert!((clock.years(jd) - years).abs() < 1e-9);
+
+        // A solar return stays within a couple of days of the mean year
+        assert!((jd - jd_tt - years * DashaYear::Sidereal.days()).abs() < 3.0);
+    }
+}
+
+#[test]
+fn solar_returns_keep_the_sidereal_year() {
+    let jd_tt = jd_tt_from_datetime(birth(1984, 3, 21, 6, 30, 5.5));
+    let sidereal = DashaYear::Sidereal.days();
+
+    // The Sun of date less a precessing ayanamsa comes back after
+    // a sidereal year; mixing in the J2000 Sun added ~20 minutes
+    // a year
+    let clock = DashaClock::new(jd_tt, DashaYear::SolarReturn);
+    let days = clock.jd(50.0) - clock.jd(0.0);
+    assert!((days - 50.0 * sidereal).abs() < 0.1, "{days}");
+
+    // Ayanamsas differ by a constant: same returns
+    let kp_mean = Compatibility {
+        nutation: NutationConvention::Mean,
+        ..Compatibility::KP
+    };
+    let kp = DashaClock::with_compat(jd_tt, DashaYear::SolarReturn, &kp_mean);
+    for years in [1.0, 10.0, 60.0] {
+        assert!((kp.jd(years) - clock.jd(years)).abs() < 1e-4);
+    }
+
+    // Apparent longitudes move the returns by minutes at most
+    let jhora = DashaClock::with_compat(jd_tt, DashaYear::SolarReturn, &Compatibility::JHORA);
+    for years in [1.0, 10.0, 60.0] {
+        assert!((jhora.jd(years) - clock.jd(years)).abs() < 0.01);
+    }
+}
@@ -0,0 +1,115 @@
+use kundli_core::dasha::nakshatra_dasha::periods_between;
+use kundli_core::dasha::rashi::{RashiChart, RashiPlacement};
+use kundli_core::dasha::sandhi::{LordPlacement, dasha_transitions, sandhi_windows};
+use kundli_core::dasha::vimshottari::{DashaLord, VIMSHOTTARI};
+use kundli_core::dasha::year::DashaClock;
+
+mod common;
+use common::SEED;
+
+use DashaLord::*;
+
+const BIRTH: f64 = 2445780.5;
+const YEAR: f64 = 365.256_363;
+
+/// Lagna Leo; every graha in its own sign
+fn chart() -> RashiChart {
+    let placements = [
+        (Sun, 4),
+        (Moon, 3),
+        (Mars, 0),
+        (Mercury, 5),
+        (Jupiter, 8),
+        (Venus, 1),
+        (Saturn, 9),
+        (Rahu, 2),
+        (Ketu, 8),
+    ]
+    .into_iter()
+    .map(|(planet, sign)| RashiPlacement {
+        planet,
+        sign,
+        degree: 15.0,
+    })
+    .collect();
+
+    RashiChart {
+        lagna: 4,
+        placements,
+    }
+}
+
+#[test]
+fn placements_count_houses_from_the_lagna() {
+    let chart = chart();
+
+    let sun = LordPlacement::of(&chart, Sun).unwrap();
+    assert_eq!((sun.sign, sun.house), (4, 1));
+
+    let moon = LordPlacement::of(&chart, Moon).unwrap();
+    assert_eq!((moon.sign, moon.house), (3, 12));
+
+    let saturn = LordPlacement::of(&chart, Saturn).unwrap();
+    assert_eq!((saturn.sign, saturn.house), (9, 6));
+}
+
+#[test]
+fn transitions_follow_the_period_boundaries() {
+    let clock = DashaClock::sidereal(BIRTH);
+    let chart = chart();
+    let (from, to) = (BIRTH + 10.0 * YEAR, BIRTH + 60.0 * YEAR);
+
+    let periods = periods_between(&VIMSHOTTARI, &clock, SEED, from, to, 2);
+    let events = dasha_transitions(&VIMSHOTTARI, &clock, SEED, &chart, from, to, 2);
+
+    assert_eq!(events.len(), periods.len() - 1);
+
+    for (e, pair) in events.iter().zip(periods.windows(2)) {
+        assert_eq!(e.jd, pair[1].start_jd);
+        assert!(e.jd >= from && e.jd < to);
+
+        assert_eq!(e.outgoing[1].lords, pair[0].lords);
+        assert_eq!(e.incoming[1].lords, pair[1].lords);
+
+        // Mahadasha transitions change the mahadasha lord
+        let maha_changes = e.outgoing[0].lord() != e.incoming[0].lord();
+        assert_eq!(e.level == 1, maha_changes);
+
+        // The periods at the transition's level change lord there
+        assert_eq!(e.outgoing_period().level(), e.level);
+        assert_eq!(e.incoming_period().level(), e.level);
+        assert_ne!(e.outgoing_period().lord(), e.incoming_period().lord());
+        assert_eq!(e.outgoing_period().end_jd, e.jd);
+        assert_eq!(e.incoming_period().start_jd, e.jd);
+
+        let lords: Vec<_> = e.incoming_placements.iter().map(|p| p.lord).collect();
+        assert_eq!(lords, pair[1].lords);
+        assert_eq!(e.outgoing_placements.len(), 2);
+    }
+
+    // 50 years hold at least two mahadasha transitions
+    assert!(events.iter().filter(|e| e.level == 1).count() >= 2);
+}
+
+#[test]
+fn sandhi_spans_a_fraction_of_the_adjoining_periods() {
+    let clock = DashaClock::sidereal(BIRTH);
+    let chart = chart();
+    let (from, to) = (BIRTH, BIRTH + 120.0 * YEAR);
+
+    for w in sandhi_windows(&VIMSHOTTARI, &clock, SEED, &chart, from, to, 2, 0.1) {
+        let t = &w.transition;
+        let out = t.outgoing_period();
+        let inc = t.incoming_period();
+
+        assert!(w.contains(t.jd));
+        assert!((t.jd - w.start_jd - 0.1 * (out.end_jd - out.start_jd)).abs() < 1e-6);
+        assert!((w.end_jd - t.jd - 0.1 * (inc.end_jd - inc.start_jd)).abs() < 1e-6);
+
+        // Mahadasha sandhi uses the mahadasha lengths
+        if t.level == 1 {
+            let years = VIMSHOTTARI.years(out.lord()).unwrap();
+            assert!((t.jd - w.start_jd - 0.1 * years * YEAR).abs() < 1e-3);
+        }
+    }
+}
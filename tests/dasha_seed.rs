@@ -0,0 +1,99 @@
+use kundli_core::PlanetJS;
+use kundli_core::astrology::nakshatra_calc::compute_nakshatra;
+use kundli_core::dasha::nakshatra_dasha::birth_balance;
+use kundli_core::dasha::rashi::RashiChart;
+use kundli_core::dasha::seed::DashaSeed;
+use kundli_core::dasha::vimshottari::{DashaLord, VIMSHOTTARI};
+
+const NAK_LEN: f64 = 360.0 / 27.0;
+
+fn planet(name: &str, sidereal_deg: f64) -> PlanetJS {
+    PlanetJS {
+        name: name.to_string(),
+        tropical_deg: sidereal_deg,
+        sidereal_deg,
+    }
+}
+
+fn rashi_chart(asc: f64, planets: &[(&str, f64)]) -> RashiChart {
+    let planets: Vec<PlanetJS> = planets.iter().map(|&(n, l)| planet(n, l)).collect();
+    RashiChart::new(asc, &planets)
+}
+
+#[test]
+fn counted_stars_from_the_moon() {
+    let chart = rashi_chart(100.0, &[]);
+
+    // Moon 30% into Krittika (3rd star)
+    let moon = 2.3 * NAK_LEN;
+
+    for (seed, star) in [
+        (DashaSeed::Moon, 2),
+        (DashaSeed::Kshema, 5),  // Ardra, 4th from Krittika
+        (DashaSeed::Utpanna, 6), // Punarvasu, 5th
+        (DashaSeed::Adhana, 9),  // Magha, 8th
+    ] {
+        let nak = compute_nakshatra(seed.longitude(moon, 100.0, &chart));
+        assert_eq!(nak.index, star, "{}", seed.name());
+        assert!((nak.fraction_elapsed - 0.3).abs() < 1e-9, "{}", seed.name());
+    }
+
+    // Counting wraps past Revati: 4th from Revati is Krittika
+    let moon = 26.5 * NAK_LEN;
+    let nak = compute_nakshatra(DashaSeed::Kshema.longitude(moon, 100.0, &chart));
+    assert_eq!(nak.index, 2);
+}
+
+#[test]
+fn counted_star_keeps_the_moons_balance() {
+    let chart = rashi_chart(100.0, &[]);
+
+    // Moon a quarter into Ashwini (Ketu); Kshema is Rohini (Moon)
+    let moon = 0.25 * NAK_LEN;
+    let seed = DashaSeed::Kshema.longitude(moon, 100.0, &chart);
+
+    let (lord, years) = birth_balance(&VIMSHOTTARI, seed);
+    assert_eq!(lord, DashaLord::Moon);
+    assert!((years - 0.75 * 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn lagna_seed_is_the_ascendant() {
+    let chart = rashi_chart(100.0, &[]);
+
+    assert_eq!(DashaSeed::Lagna.longitude(50.0, 100.0, &chart), 100.0);
+    assert!((DashaSeed::Lagna.longitude(50.0, 370.0, &chart) - 10.0).abs() < 1e-9);
+    assert!((DashaSeed::Moon.longitude(-10.0, 100.0, &chart) - 350.0).abs() < 1e-9);
+}
+
+#[test]
+fn stronger_of_moon_and_lagna() {
+    let (moon, asc) = (200.0, 100.0); // Libra, Cancer
+
+    // Two planets with the Moon in Libra against one in Cancer
+    let chart = rashi_chart(asc, &[("Moon", moon), ("Saturn", 205.0), ("Jupiter", 95.0)]);
+    assert_eq!(DashaSeed::Stronger.longitude(moon, asc, &chart), moon);
+
+    // Cancer holds more planets
+    let chart = rashi_chart(asc, &[("Moon", moon), ("Jupiter", 95.0), ("Mars", 110.0)]);
+    assert_eq!(DashaSeed::Stronger.longitude(moon, asc, &chart), asc);
+
+    // Moon in the lagna sign: either way the Moon
+    let chart = rashi_chart(asc, &[("Moon", 105.0)]);
+    assert_eq!(DashaSeed::Stronger.longitude(105.0, asc, &chart), 105.0);
+}
+
+#[test]
+fn names_round_trip() {
+    for seed in [
+        DashaSeed::Moon,
+        DashaSeed::Lagna,
+        DashaSeed::Stronger,
+        DashaSeed::Kshema,
+        DashaSeed::Utpanna,
+        DashaSeed::Adhana,
+    ] {
+        assert_eq!(DashaSeed::from_name(seed.name()), Some(seed));
+    }
+    assert_eq!(DashaSeed::from_name("Sun"), None);
+}
@@ -0,0 +1,133 @@
+use kundli_core::dasha::nakshatra_dasha::{
+    DashaStream, MAX_DEPTH, active_chain, nakshatra_antardasha_timeline,
+    nakshatra_mahadasha_timeline, periods_between, sub_periods,
+};
+use kundli_core::dasha::vimshottari::VIMSHOTTARI;
+use kundli_core::dasha::year::{DashaClock, DashaYear};
+
+mod common;
+use common::{SEED, date};
+
+fn clock() -> DashaClock {
+    DashaClock::new(date(1984, 3, 21), DashaYear::Sidereal)
+}
+
+#[test]
+fn stream_matches_the_first_cycle() {
+    let clock = clock();
+
+    let maha = nakshatra_mahadasha_timeline(&VIMSHOTTARI, &clock, SEED);
+    let stream: Vec<_> = DashaStream::new(&VIMSHOTTARI, clock, SEED, 1)
+        .take(maha.len())
+        .collect();
+
+    for (m, s) in maha.iter().zip(&stream) {
+        assert_eq!(s.lords, vec![m.lord]);
+        assert_eq!((s.start_jd, s.end_jd), (m.start_jd, m.end_jd));
+    }
+
+    let antara = nakshatra_antardasha_timeline(&VIMSHOTTARI, &clock, SEED);
+    let stream = DashaStream::new(&VIMSHOTTARI, clock, SEED, 2);
+
+    // First antardasha running at birth, then every one after it
+    let first = antara
+        .iter()
+        .position(|a| a.end_jd > clock.birth_jd)
+        .unwrap();
+    for (a, s) in antara[first..].iter().zip(stream) {
+        assert_eq!(s.lords, vec![a.maha, a.antara]);
+        assert!((s.start_jd - a.start_jd).abs() < 1e-6);
+        assert!((s.end_jd - a.end_jd).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn stream_continues_past_the_cycle() {
+    let clock = clock();
+
+    let maha = nakshatra_mahadasha_timeline(&VIMSHOTTARI, &clock, SEED);
+    let two_cycles: Vec<_> = DashaStream::new(&VIMSHOTTARI, clock, SEED, 1)
+        .take(2 * maha.len())
+        .collect();
+
+    for (i, m) in maha.iter().enumerate() {
+        let again = &two_cycles[i + maha.len()];
+        assert_eq!(again.lord(), m.lord);
+        assert!((again.start_jd - m.start_jd - 120.0 * DashaYear::Sidereal.days()).abs() < 1e-6);
+    }
+
+    // Periods are contiguous at every level
+    let deep: Vec<_> = DashaStream::new(&VIMSHOTTARI, clock, SEED, 3)
+        .take(2000)
+        .collect();
+    for pair in deep.windows(2) {
+        assert_eq!(pair[0].end_jd, pair[1].start_jd);
+    }
+}
+
+#[test]
+fn seeking_lands_on_the_active_chain() {
+    let clock = clock();
+
+    // Before birth, in the first cycle and beyond it
+    for jd in [
+        date(1900, 6, 1),
+        date(2035, 1, 1),
+        date(2150, 1, 1),
+        date(2400, 1, 1),
+    ] {
+        let chain = active_chain(&VIMSHOTTARI, &clock, SEED, jd, 4);
+        assert_eq!(chain.len(), 4);
+
+        for (level, node) in chain.iter().enumerate() {
+            assert_eq!(node.level(), level + 1);
+            assert!(node.contains(jd), "level {} at {jd}", level + 1);
+        }
+
+        // Each level is one of its parent's sub-periods
+        for pair in chain.windows(2) {
+            let subs = sub_periods(&VIMSHOTTARI, &clock, &pair[0]);
+            assert!(subs.iter().any(|s| s.lords == pair[1].lords));
+        }
+    }
+}
+
+#[test]
+fn window_covers_the_requested_dates() {
+    let clock = clock();
+    let (from, to) = (date(2030, 1, 1), date(2040, 1, 1));
+
+    let window = periods_between(&VIMSHOTTARI, &clock, SEED, from, to, 2);
+
+    assert!(window[0].contains(from));
+    assert!(window[window.len() - 1].end_jd >= to);
+    assert!(window[window.len() - 1].start_jd < to);
+    for pair in window.windows(2) {
+        assert_eq!(pair[0].end_jd, pair[1].start_jd);
+    }
+}
+
+#[test]
+fn sub_periods_end_with_their_parent() {
+    let clock = clock();
+    let jd = date(2031, 7, 9);
+    let chain = active_chain(&VIMSHOTTARI, &clock, SEED, jd, MAX_DEPTH);
+    assert_eq!(chain.len(), MAX_DEPTH);
+
+    for (level, node) in chain.iter().enumerate() {
+        let subs = sub_periods(&VIMSHOTTARI, &clock, node);
+
+        assert_eq!(subs[0].start_jd, node.start_jd);
+        assert_eq!(subs[subs.len() - 1].end_jd, node.end_jd);
+        for pair in subs.windows(2) {
+            assert_eq!(pair[0].end_jd, pair[1].start_jd);
+        }
+
+        // Same periods as the stream one level down
+        if let Some(child) = chain.get(level + 1) {
+            let sub = subs.iter().find(|s| s.lords == child.lords).unwrap();
+            assert!((sub.start_jd - child.start_jd).abs() < 1e-9);
+            assert!((sub.end_jd - child.end_jd).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::f64::consts::{PI, TAU};
+
+use kundli_core::astrology::houses::{HouseError, HouseSystem, ascendant_from_lst, house_cusps};
+
+const OBLIQ: f64 = 0.409_092_804; // ~23.4393°
+
+type Vec3 = [f64; 3];
+
+fn wrapped_diff(a: f64, b: f64) -> f64 {
+    ((a - b + PI).rem_euclid(TAU) - PI).abs()
+}
+
+fn dot(a: Vec3, b: Vec3) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Equatorial unit vector of an ecliptic longitude
+fn ecliptic_point(lon: f64) -> Vec3 {
+    [lon.cos(), lon.sin() * OBLIQ.cos(), lon.sin() * OBLIQ.sin()]
+}
+
+/// Zenith, east point and north point of the horizon
+fn horizon_frame(ramc: f64, lat: f64) -> (Vec3, Vec3, Vec3) {
+    let zenith = [lat.cos() * ramc.cos(), lat.cos() * ramc.sin(), lat.sin()];
+    let east = [-ramc.sin(), ramc.cos(), 0.0];
+    let north = cross(zenith, east);
+    (zenith, east, north)
+}
+
+fn mc_from_ramc(ramc: f64) -> f64 {
+    ramc.sin().atan2(ramc.cos() * OBLIQ.cos()).rem_euclid(TAU)
+}
+
+fn cusps(system: HouseSystem, ramc: f64, lat: f64) -> [f64; 12] {
+    let asc = ascendant_from_lst(ramc, lat, OBLIQ);
+    house_cusps(system, asc, mc_from_ramc(ramc), lat, OBLIQ).unwrap()
+}
+
+/// Cusps 11, 12, 2 and 3 lie on the house circles through the
+/// north and south points whose eastern halves pass through
+/// `through` (one point per cusp).
+fn assert_on_house_circles(
+    cusps: &[f64; 12],
+    east: Vec3,
+    north: Vec3,
+    through: [Vec3; 4],
+    tag: &str,
+) {
+    for (house, q) in [11, 12, 2, 3].into_iter().zip(through) {
+        let v = ecliptic_point(cusps[house - 1]);
+        let pole = cross(north, q);
+
+        assert!(
+            dot(v, pole).abs() < 1e-9,
+            "{tag} cusp {house}: off its circle"
+        );
+        assert!(dot(v, east) > 0.0, "{tag} cusp {house}: not east");
+    }
+}
+
+const SAMPLES: [(f64, f64); 6] = [
+    (51.5, 0.0),
+    (51.5, 100.0),
+    (51.5, 250.0),
+    (-33.9, 30.0),
+    (-33.9, 180.0),
+    (-33.9, 300.0),
+];
+
+#[test]
+fn regiomontanus_divides_the_equator() {
+    for (lat_deg, ramc_deg) in SAMPLES {
+        let (lat, ramc) = (lat_deg.to_radians(), ramc_deg.to_radians());
+        let (_, east, north) = horizon_frame(ramc, lat);
+
+        // Equator points 30°, 60°, 120° and 150° east of the meridian
+        let equator = |h: f64| {
+            let ra = ramc + h.to_radians();
+            [ra.cos(), ra.sin(), 0.0]
+        };
+
+        assert_on_house_circles(
+            &cusps(HouseSystem::Regiomontanus, ramc, lat),
+            east,
+            north,
+            [equator(30.0), equator(60.0), equator(120.0), equator(150.0)],
+            &format!("Regiomontanus lat {lat_deg} RAMC {ramc_deg}"),
+        );
+    }
+}
+
+#[test]
+fn campanus_divides_the_prime_vertical() {
+    for (lat_deg, ramc_deg) in SAMPLES {
+        let (lat, ramc) = (lat_deg.to_radians(), ramc_deg.to_radians());
+        let (zenith, east, north) = horizon_frame(ramc, lat);
+
+        // Prime vertical points at the given altitude in the east
+        let prime_vertical = |alt: f64| {
+            let (s, c) = alt.to_radians().sin_cos();
+            [
+                c * east[0] + s * zenith[0],
+                c * east[1] + s * zenith[1],
+                c * east[2] + s * zenith[2],
+            ]
+        };
+
+        assert_on_house_circles(
+            &cusps(HouseSystem::Campanus, ramc, lat),
+            east,
+            north,
+            [
+                prime_vertical(60.0),
+                prime_vertical(30.0),
+                prime_vertical(-30.0),
+                prime_vertical(-60.0),
+            ],
+            &format!("Campanus lat {lat_deg} RAMC {ramc_deg}"),
+        );
+    }
+}
+
+#[test]
+fn koch_cusps_rise_at_trisections_of_the_mc_semi_arc() {
+    for (lat_deg, ramc_deg) in SAMPLES {
+        let (lat, ramc) = (lat_deg.to_radians(), ramc_deg.to_radians());
+        let c = cusps(HouseSystem::Koch, ramc, lat);
+
+        let mc_decl = (mc_from_ramc(ramc).sin() * OBLIQ.sin()).asin();
+        let semi_arc = (-lat.tan() * mc_decl.tan()).acos();
+
+        // Each cusp is on the eastern horizon at its sidereal time
+        for (house, lst) in [
+            (11, ramc - 2.0 * semi_arc / 3.0),
+            (12, ramc - semi_arc / 3.0),
+            (2, ramc + semi_arc / 3.0),
+            (3, ramc + 2.0 * semi_arc / 3.0),
+        ] {
+            let v = ecliptic_point(c[house - 1]);
+            let (zenith, east, _) = horizon_frame(lst, lat);
+
+            assert!(
+                dot(v, zenith).abs() < 1e-9,
+                "lat {lat_deg} RAMC {ramc_deg} cusp {house}: not on horizon"
+            );
+            assert!(
+                dot(v, east) > 0.0,
+                "lat {lat_deg} RAMC {ramc_deg} cusp {house}: not east"
+            );
+        }
+    }
+}
+
+#[test]
+fn quadrant_systems_agree_on_the_equator() {
+    // On the equator every house circle is an hour circle, so
+    // cusp n has right ascension RAMC + 90° + 30°·(n − 1)
+    for ramc_deg in [0.0, 75.0, 200.0] {
+        let ramc = f64::to_radians(ramc_deg);
+
+        for system in [
+            HouseSystem::Regiomontanus,
+            HouseSystem::Campanus,
+            HouseSystem::Koch,
+        ] {
+            let c = cusps(system, ramc, 0.0);
+
+            for (i, cusp) in c.iter().enumerate() {
+                let ra = ramc + f64::to_radians(90.0 + 30.0 * i as f64);
+                let expected = mc_from_ramc(ra);
+                assert!(
+                    wrapped_diff(*cusp, expected) < 1e-9,
+                    "{} RAMC {ramc_deg} cusp {}: {} vs {}",
+                    system.name(),
+                    i + 1,
+                    cusp.to_degrees(),
+                    expected.to_degrees()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn cusps_run_forward_around_the_zodiac() {
+    for (lat_deg, ramc_deg) in SAMPLES {
+        let (lat, ramc) = (lat_deg.to_radians(), ramc_deg.to_radians());
+
+        for system in [
+            HouseSystem::Regiomontanus,
+            HouseSystem::Campanus,
+            HouseSystem::Koch,
+        ] {
+            let c = cusps(system, ramc, lat);
+            let total: f64 = (0..12)
+                .map(|i| (c[(i + 1) % 12] - c[i]).rem_euclid(TAU))
+                .sum();
+
+            assert!(
+                (total - TAU).abs() < 1e-9,
+                "{} lat {lat_deg} RAMC {ramc_deg}: houses overlap",
+                system.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn koch_is_undefined_inside_the_polar_circles() {
+    let ramc = f64::to_radians(120.0);
+
+    for lat_deg in [67.0, 75.0, -70.0] {
+        let lat = f64::to_radians(lat_deg);
+        let asc = ascendant_from_lst(ramc, lat, OBLIQ);
+        let mc = mc_from_ramc(ramc);
+
+        assert_eq!(
+            house_cusps(HouseSystem::Koch, asc, mc, lat, OBLIQ),
+            Err(HouseError::CircumpolarLatitude),
+            "lat {lat_deg}"
+        );
+        assert!(house_cusps(HouseSystem::Regiomontanus, asc, mc, lat, OBLIQ).is_ok());
+        assert!(house_cusps(HouseSystem::Campanus, asc, mc, lat, OBLIQ).is_ok());
+    }
+
+    // Just outside the polar circle Koch still works
+    let lat = f64::to_radians(66.0);
+    let asc = ascendant_from_lst(ramc, lat, OBLIQ);
+    assert!(house_cusps(HouseSystem::Koch, asc, mc_from_ramc(ramc), lat, OBLIQ).is_ok());
+}
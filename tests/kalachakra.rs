@@ -0,0 +1,93 @@
+use kundli_core::dasha::kalachakra::{
+    Chakra, Gati, gati, kalachakra_birth, kalachakra_mahadasha_timeline, kalachakra_pada,
+};
+use kundli_core::dasha::year::{DashaClock, DashaYear};
+
+const NAK_LEN: f64 = 360.0 / 27.0;
+
+fn clock() -> DashaClock {
+    DashaClock::new(2451545.0, DashaYear::Julian)
+}
+
+#[test]
+fn ashwini_first_pada() {
+    let p = kalachakra_pada(0, 1);
+
+    assert_eq!(p.chakra, Chakra::Savya);
+    assert_eq!(p.signs, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!((p.deha, p.jeeva), (0, 8));
+    assert_eq!(p.paramayus, 100.0);
+
+    // Savya Ashwini padas: 100, 85, 83, 86 years
+    let paramayus: Vec<f64> = (1..=4).map(|n| kalachakra_pada(0, n).paramayus).collect();
+    assert_eq!(paramayus, [100.0, 85.0, 83.0, 86.0]);
+}
+
+#[test]
+fn rohini_first_pada_mirrors_bharani_fourth() {
+    let bharani = kalachakra_pada(1, 4);
+    let rohini = kalachakra_pada(3, 1);
+
+    assert_eq!(bharani.chakra, Chakra::Savya);
+    assert_eq!(rohini.chakra, Chakra::Apasavya);
+
+    let mut reversed = bharani.signs;
+    reversed.reverse();
+    assert_eq!(rohini.signs, reversed);
+    assert_eq!(rohini.signs, [8, 9, 10, 11, 0, 1, 2, 4, 3]);
+    assert_eq!((rohini.deha, rohini.jeeva), (8, 3));
+    assert_eq!(rohini.paramayus, 86.0);
+}
+
+#[test]
+fn gati_of_single_moves() {
+    // Ashwini pada 2: Capricorn, Aquarius, Pisces, Scorpio, Libra,
+    // Virgo, Cancer, Leo, Gemini
+    assert_eq!(gati(9, 10, None), Gati::Normal);
+
+    // Pisces → Scorpio: lion's glance to the trine
+    assert_eq!(gati(11, 7, Some((10, 11))), Gati::Simhavalokana);
+    // Sagittarius → Aries (Ashwini pada 3)
+    assert_eq!(gati(8, 0, Some((9, 8))), Gati::Simhavalokana);
+
+    // Virgo → Cancer: frog jump over Leo
+    assert_eq!(gati(5, 3, Some((6, 5))), Gati::Manduka);
+
+    // Cancer → Leo after moving backwards: monkey step
+    assert_eq!(gati(3, 4, Some((5, 3))), Gati::Markati);
+    // The same step in a forward run is normal
+    assert_eq!(gati(3, 4, Some((2, 3))), Gati::Normal);
+}
+
+#[test]
+fn timeline_marks_the_transitions() {
+    // Start of Krittika: pada 1 has the Ashwini pada 1 path,
+    // followed by pada 2 (the Ashwini pada 2 path)
+    let moon = 2.0 * NAK_LEN + 1e-9;
+    let birth = kalachakra_birth(moon);
+    assert_eq!(birth.nakshatra_name, "Krittika");
+    assert_eq!(birth.pada.pada, 1);
+    assert!(birth.elapsed_years < 1e-6);
+
+    let timeline = kalachakra_mahadasha_timeline(&clock(), moon);
+    let moves: Vec<(u8, Gati)> = timeline[8..18].iter().map(|d| (d.sign, d.gati)).collect();
+
+    assert_eq!(
+        moves,
+        [
+            (8, Gati::Normal),        // Sagittarius
+            (9, Gati::Normal),        // Capricorn, next pada
+            (10, Gati::Normal),       // Aquarius
+            (11, Gati::Normal),       // Pisces
+            (7, Gati::Simhavalokana), // Scorpio
+            (6, Gati::Normal),        // Libra
+            (5, Gati::Normal),        // Virgo
+            (3, Gati::Manduka),       // Cancer
+            (4, Gati::Markati),       // Leo
+            (2, Gati::Manduka),       // Gemini
+        ]
+    );
+
+    assert_eq!(timeline[9].pada.pada, 2);
+    assert!((timeline[8].end_jd - (2451545.0 + 100.0 * 365.25)).abs() < 1e-3);
+}
@@ -0,0 +1,185 @@
+use kundli_core::PlanetJS;
+use kundli_core::astrology::houses::Sign;
+use kundli_core::astrology::kp::{
+    house_significators, kp_horary_ascendant, kp_house_of, kp_lords, kp_sub_table,
+    planet_significations,
+};
+use kundli_core::dasha::vimshottari::{DashaLord, dasha_years};
+
+use DashaLord::*;
+
+const NAK_LEN: f64 = 360.0 / 27.0;
+
+fn planet(name: &str, sidereal_deg: f64) -> PlanetJS {
+    PlanetJS {
+        name: name.to_string(),
+        tropical_deg: sidereal_deg,
+        sidereal_deg,
+    }
+}
+
+/// Aries rising with equal 30° cusps, so house = sign
+fn cusps() -> [f64; 12] {
+    std::array::from_fn(|i| i as f64 * 30.0)
+}
+
+fn chart() -> Vec<PlanetJS> {
+    vec![
+        planet("Sun", 10.0),      // Ashwini (Ketu), 1st
+        planet("Moon", 45.0),     // Rohini (Moon), 2nd
+        planet("Mercury", 130.0), // Magha (Ketu), 5th
+        planet("Venus", 20.0),    // Bharani (Venus), 1st
+        planet("Mars", 100.0),    // Pushya (Saturn), 4th
+        planet("Jupiter", 200.0), // Vishakha (Jupiter), 7th
+        planet("Saturn", 300.0),  // Dhanishta (Mars), 11th
+        planet("Rahu", 70.0),     // Ardra (Rahu), 3rd
+        planet("Ketu", 250.0),    // Mula (Ketu), 9th
+    ]
+}
+
+#[test]
+fn table_has_249_contiguous_subs() {
+    let table = kp_sub_table();
+    assert_eq!(table.len(), 249);
+
+    assert_eq!(table[0].start_deg, 0.0);
+    assert!((table[248].end_deg - 360.0).abs() < 1e-9);
+
+    for (i, s) in table.iter().enumerate() {
+        assert_eq!(s.number as usize, i + 1);
+        assert!(s.end_deg > s.start_deg, "sub {}", s.number);
+        if i > 0 {
+            assert!((table[i - 1].end_deg - s.start_deg).abs() < 1e-9);
+        }
+        // No sub crosses a sign boundary
+        assert_eq!(
+            (s.start_deg / 30.0 + 1e-9).floor(),
+            (s.end_deg / 30.0 - 1e-9).floor(),
+            "sub {}",
+            s.number
+        );
+    }
+}
+
+#[test]
+fn sign_boundaries_split_subs() {
+    let table = kp_sub_table();
+
+    // Krittika's Rahu sub (29°13′20″ – 31°13′20″) straddles Taurus
+    let (aries, taurus) = (&table[21], &table[22]);
+    assert_eq!((aries.star_lord, aries.sub_lord), (Sun, Rahu));
+    assert_eq!((taurus.star_lord, taurus.sub_lord), (Sun, Rahu));
+    assert_eq!(aries.sign, Sign::Aries);
+    assert_eq!(taurus.sign, Sign::Taurus);
+    assert!((aries.end_deg - 30.0).abs() < 1e-9);
+    assert!((taurus.end_deg - (29.0 + 13.0 / 60.0 + 20.0 / 3600.0 + 2.0)).abs() < 1e-9);
+}
+
+#[test]
+fn subs_follow_vimshottari_proportions() {
+    let table = kp_sub_table();
+
+    // Rejoin subs split at a sign boundary
+    let mut subs: Vec<(DashaLord, DashaLord, f64, f64)> = Vec::new();
+    for s in &table {
+        match subs.last_mut() {
+            Some(last) if (last.0, last.1) == (s.star_lord, s.sub_lord) => last.3 = s.end_deg,
+            _ => subs.push((s.star_lord, s.sub_lord, s.start_deg, s.end_deg)),
+        }
+    }
+    assert_eq!(subs.len(), 27 * 9);
+
+    for (i, nak) in subs.chunks(9).enumerate() {
+        // Each nakshatra starts with its own lord's sub
+        assert_eq!(nak[0].0, nak[0].1, "nakshatra {i}");
+        assert!((nak[0].2 - i as f64 * NAK_LEN).abs() < 1e-9);
+
+        for (star, sub, start, end) in nak {
+            let expected = NAK_LEN * dasha_years(*sub) / 120.0;
+            assert!(
+                (end - start - expected).abs() < 1e-9,
+                "{star:?}/{sub:?}: {}",
+                end - start
+            );
+        }
+        for pair in nak.windows(2) {
+            assert_eq!(pair[1].1, pair[0].1.next());
+        }
+    }
+
+    // Ashwini: Ketu 0°00′–0°46′40″, Venus to 3°00′
+    assert!((subs[0].3 - 7.0 / 9.0).abs() < 1e-9);
+    assert!((subs[1].3 - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn horary_numbers() {
+    assert_eq!(kp_horary_ascendant(1), Some(0.0));
+    assert_eq!(kp_horary_ascendant(0), None);
+    assert_eq!(kp_horary_ascendant(250), None);
+
+    // 23 is the first sub of Taurus
+    assert!((kp_horary_ascendant(23).unwrap() - 30.0).abs() < 1e-9);
+
+    // 249: Revati's Saturn sub, 357°53′20″
+    let last = kp_horary_ascendant(249).unwrap();
+    assert!((last - (357.0 + 53.0 / 60.0 + 20.0 / 3600.0)).abs() < 1e-9);
+}
+
+#[test]
+fn lords_of_a_longitude() {
+    // 0.5° Aries: Ketu star, Ketu sub, Jupiter sub-sub
+    let l = kp_lords(0.5);
+    assert_eq!(
+        (l.sign_lord, l.star_lord, l.sub_lord, l.sub_sub_lord),
+        (Mars, Ketu, Ketu, Jupiter)
+    );
+
+    // 45° Taurus: 5° into Rohini, past the Moon, Mars and Rahu subs
+    let l = kp_lords(45.0);
+    assert_eq!(
+        (l.sign_lord, l.star_lord, l.sub_lord),
+        (Venus, Moon, Jupiter)
+    );
+}
+
+#[test]
+fn house_significators_of_a_fixed_chart() {
+    let planets = chart();
+    let cusps = cusps();
+
+    assert_eq!(kp_house_of(10.0, &cusps), 1);
+    assert_eq!(kp_house_of(300.0, &cusps), 11);
+
+    let h1 = house_significators(1, &planets, &cusps);
+    assert_eq!(h1.occupants, vec![Sun, Venus]);
+    assert_eq!(h1.star_of_occupants, vec![Venus]);
+    assert_eq!(h1.owner, Mars);
+    assert_eq!(h1.star_of_owner, vec![Saturn]);
+
+    let h9 = house_significators(9, &planets, &cusps);
+    assert_eq!(h9.occupants, vec![Ketu]);
+    assert_eq!(h9.star_of_occupants, vec![Sun, Mercury, Ketu]);
+    assert_eq!(h9.owner, Jupiter);
+    assert_eq!(h9.star_of_owner, vec![Jupiter]);
+}
+
+#[test]
+fn planet_significations_of_a_fixed_chart() {
+    let planets = chart();
+    let cusps = cusps();
+
+    // Saturn in the 11th, in the star of Mars in the 4th
+    let s = planet_significations(Saturn, &planets, &cusps).unwrap();
+    assert_eq!(s.star_lord_occupies, 4);
+    assert_eq!(s.occupies, 11);
+    assert_eq!(s.star_lord_owns, vec![1, 8]);
+    assert_eq!(s.owns, vec![10, 11]);
+
+    // Rahu in Gemini acts for Mercury
+    let r = planet_significations(Rahu, &planets, &cusps).unwrap();
+    assert_eq!(r.star_lord_occupies, 3);
+    assert_eq!(r.occupies, 3);
+    assert_eq!(r.star_lord_owns, vec![3, 6]);
+    assert_eq!(r.owns, vec![3, 6]);
+}
@@ -0,0 +1,144 @@
+use kundli_core::compat::Compatibility;
+use kundli_core::panchang::limbs::{Limb, LunarLimb, karana_name, lunar_limb, lunar_limbs_between};
+use kundli_core::panchang::rise_set::{Body, Horizon, body_altitude, event_after};
+use kundli_core::panchang::{panchang_at, vara, weekday};
+use kundli_core::time::datetime::{DateTimeInput, jd_tt_from_datetime};
+
+const DELHI: (f64, f64) = (28.6139, 77.209);
+const COMPAT: Compatibility = Compatibility::ASTRONOMICAL;
+
+fn ist(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> f64 {
+    jd_tt_from_datetime(DateTimeInput {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second: 0.0,
+        tz_offset_hours: 5.5,
+    })
+}
+
+#[test]
+fn limbs_contain_the_moment_and_meet_their_neighbours() {
+    let jd = ist(2024, 1, 11, 12, 0);
+
+    for limb in [
+        LunarLimb::Tithi,
+        LunarLimb::Nakshatra,
+        LunarLimb::Yoga,
+        LunarLimb::Karana,
+    ] {
+        let p = lunar_limb(limb, jd, &COMPAT);
+        assert!(p.contains(jd), "{limb:?}");
+        assert_eq!(p.limb, limb.limb());
+        // Every lunar limb lasts well under two days
+        assert!(p.end_jd - p.start_jd > 0.2 && p.end_jd - p.start_jd < 1.7);
+
+        // Just past either boundary lies the neighbouring part
+        let before = lunar_limb(limb, p.start_jd - 1e-5, &COMPAT);
+        let after = lunar_limb(limb, p.end_jd + 1e-5, &COMPAT);
+        assert_ne!(before.index, p.index);
+        assert_ne!(after.index, p.index);
+        assert!((before.end_jd - p.start_jd).abs() < 1e-6);
+        assert!((after.start_jd - p.end_jd).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn karana_is_half_a_tithi() {
+    let from = ist(2024, 3, 1, 0, 0);
+
+    for t in lunar_limbs_between(LunarLimb::Tithi, from, from + 30.0, &COMPAT) {
+        let first = lunar_limb(LunarLimb::Karana, t.start_jd + 1e-4, &COMPAT);
+        let second = lunar_limb(LunarLimb::Karana, t.end_jd - 1e-4, &COMPAT);
+
+        assert_eq!(first.index, 2 * t.index);
+        assert_eq!(second.index, 2 * t.index + 1);
+        assert!((first.start_jd - t.start_jd).abs() < 1e-6);
+        assert!((second.end_jd - t.end_jd).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn a_month_holds_every_tithi_once() {
+    let from = ist(2024, 5, 1, 0, 0);
+    let tithis = lunar_limbs_between(LunarLimb::Tithi, from, from + 29.0, &COMPAT);
+
+    for pair in tithis.windows(2) {
+        assert_eq!(pair[0].end_jd, pair[1].start_jd);
+        assert_eq!(pair[1].index, (pair[0].index + 1) % 30);
+    }
+
+    let mut seen: Vec<_> = tithis.iter().map(|t| t.index).collect();
+    seen.sort();
+    seen.dedup();
+    assert!(seen.len() >= 29);
+}
+
+#[test]
+fn karana_names_follow_the_fixed_and_movable_cycle() {
+    assert_eq!(karana_name(0), "Kimstughna");
+    assert_eq!(karana_name(1), "Bava");
+    assert_eq!(karana_name(7), "Vishti");
+    assert_eq!(karana_name(8), "Bava");
+    assert_eq!(karana_name(56), "Vishti");
+    assert_eq!(karana_name(57), "Shakuni");
+    assert_eq!(karana_name(58), "Chatushpada");
+    assert_eq!(karana_name(59), "Naga");
+}
+
+#[test]
+fn vara_runs_from_sunrise_to_sunrise() {
+    let (lat, lon) = (DELHI.0.to_radians(), DELHI.1.to_radians());
+
+    // Thursday noon, and Friday before sunrise (still Thursday)
+    for jd in [ist(2024, 1, 11, 12, 0), ist(2024, 1, 12, 5, 0)] {
+        let v = vara(jd, lat, lon).unwrap();
+        assert_eq!(v.name, "Guruvara");
+        assert!(v.contains(jd));
+
+        // Sun on the horizon at both ends
+        for t in [v.start_jd, v.end_jd] {
+            assert!((body_altitude(Body::Sun, t, lat, lon).to_degrees() + 0.8333).abs() < 1e-3);
+        }
+    }
+
+    assert_eq!(weekday(ist(2024, 1, 11, 12, 0), lon), 4);
+}
+
+#[test]
+fn delhi_sunrise_is_near_the_almanac() {
+    let (lat, lon) = (DELHI.0.to_radians(), DELHI.1.to_radians());
+
+    // 2024-01-01: 07:14 IST in published tables
+    let rise = event_after(
+        Body::Sun,
+        Horizon::Rise,
+        ist(2024, 1, 1, 0, 0),
+        1.0,
+        lat,
+        lon,
+    )
+    .unwrap();
+    assert!((rise - ist(2024, 1, 1, 7, 14)).abs() < 3.0 / 1440.0);
+}
+
+#[test]
+fn polar_night_has_no_vara() {
+    let jd = ist(2024, 12, 21, 12, 0);
+    assert!(vara(jd, 80f64.to_radians(), 15f64.to_radians()).is_none());
+
+    let p = panchang_at(jd, 80f64.to_radians(), 15f64.to_radians(), &COMPAT);
+    assert!(p.vara.is_none());
+    assert!(p.tithi.contains(jd));
+}
+
+#[test]
+fn vara_is_not_a_lunar_limb() {
+    assert_eq!(Limb::Vara.lunar(), None);
+
+    for limb in [Limb::Tithi, Limb::Nakshatra, Limb::Yoga, Limb::Karana] {
+        assert_eq!(limb.lunar().map(|l| l.limb()), Some(limb));
+    }
+}
@@ -0,0 +1,166 @@
+use kundli_core::compat::Compatibility;
+use kundli_core::panchang::daily::{Ayana, DailyPanchang, Paksha, Ritu, daily_panchang};
+use kundli_core::panchang::limbs::LimbPeriod;
+use kundli_core::panchang::rise_set::{Body, body_altitude};
+use kundli_core::time::datetime::{DateTimeInput, jd_tt_from_datetime};
+
+const COMPAT: Compatibility = Compatibility::ASTRONOMICAL;
+
+fn delhi(year: i32, month: u8, day: u8, days: usize) -> Vec<DailyPanchang> {
+    daily_panchang(
+        year,
+        month,
+        day,
+        days,
+        5.5,
+        28.6139f64.to_radians(),
+        77.209f64.to_radians(),
+        &COMPAT,
+    )
+}
+
+/// Parts cover `from .. to` without gaps
+fn assert_covers(parts: &[LimbPeriod], from: f64, to: f64) {
+    assert!(parts[0].contains(from));
+    assert!(parts[parts.len() - 1].end_jd >= to);
+    for pair in parts.windows(2) {
+        assert_eq!(pair[0].end_jd, pair[1].start_jd);
+    }
+}
+
+#[test]
+fn days_run_from_sunrise_to_sunrise() {
+    let days = delhi(2024, 2, 27, 5);
+
+    let dates: Vec<_> = days.iter().map(|d| (d.month, d.day)).collect();
+    assert_eq!(dates, [(2, 27), (2, 28), (2, 29), (3, 1), (3, 2)]);
+
+    for pair in days.windows(2) {
+        let (today, tomorrow) = (&pair[0], &pair[1]);
+        let sunrise = today.sunrise.unwrap();
+        let next = tomorrow.sunrise.unwrap();
+
+        assert!(sunrise > today.midnight_jd && sunrise < today.sunset.unwrap());
+
+        let vara = today.vara.unwrap();
+        assert!((vara.start_jd - sunrise).abs() < 1e-5);
+        assert!((vara.end_jd - next).abs() < 1e-5);
+        assert_eq!(tomorrow.vara.unwrap().index, (vara.index + 1) % 7);
+
+        for limbs in [
+            &today.tithis,
+            &today.nakshatras,
+            &today.yogas,
+            &today.karanas,
+        ] {
+            assert_covers(limbs, sunrise, next);
+        }
+
+        // The limb running at the next sunrise opens tomorrow's list
+        assert_eq!(
+            today.tithis[today.tithis.len() - 1].index,
+            tomorrow.tithis[0].index
+        );
+        assert_eq!(today.paksha, Paksha::of_tithi(today.tithis[0].index));
+    }
+}
+
+#[test]
+fn moon_rises_on_the_horizon() {
+    let (lat, lon) = (28.6139f64.to_radians(), 77.209f64.to_radians());
+    let days = delhi(2024, 3, 1, 30);
+
+    // The Moon skips about one rise and one set a month
+    assert!(days.iter().filter(|d| d.moonrise.is_some()).count() >= 28);
+    assert!(days.iter().filter(|d| d.moonset.is_some()).count() >= 28);
+
+    for d in &days {
+        for jd in [d.moonrise, d.moonset].into_iter().flatten() {
+            assert!(jd > d.midnight_jd && jd < d.midnight_jd + 1.0);
+            let alt = body_altitude(Body::Moon, jd, lat, lon).to_degrees();
+            assert!((alt - 0.125).abs() < 1e-3);
+        }
+    }
+}
+
+#[test]
+fn signs_ayana_and_ritu_follow_the_sidereal_sun() {
+    // Sun in sidereal Sagittarius, then in Capricorn after
+    // Makara sankranti
+    let before = &delhi(2024, 1, 1, 1)[0];
+    assert_eq!(before.sun_signs[0].sign, 8);
+    assert_eq!(before.ayana, Ayana::Dakshinayana);
+    assert_eq!(before.ritu, Ritu::Hemanta);
+
+    let after = &delhi(2024, 1, 20, 1)[0];
+    assert_eq!(after.sun_signs[0].sign, 9);
+    assert_eq!(after.ayana, Ayana::Uttarayana);
+    assert_eq!(after.ritu, Ritu::Shishira);
+
+    // The Moon changes sign about every 2.5 days
+    let month = delhi(2024, 1, 1, 27);
+    let changes: usize = month.iter().map(|d| d.moon_signs.len() - 1).sum();
+    assert!((10..=13).contains(&changes));
+}
+
+/// Sun sign changes in Delhi, 2024, with the JHora settings
+fn sankranti(month: u8, day: u8, sign: u8) -> f64 {
+    let days = daily_panchang(
+        2024,
+        month,
+        day,
+        1,
+        5.5,
+        28.6139f64.to_radians(),
+        77.209f64.to_radians(),
+        &Compatibility::JHORA,
+    );
+    let entry = days[0].sun_signs.iter().find(|s| s.sign == sign).unwrap();
+    entry.start_jd
+}
+
+fn ist(month: u8, day: u8, hour: u8, minute: u8) -> f64 {
+    jd_tt_from_datetime(DateTimeInput {
+        year: 2024,
+        month,
+        day,
+        hour,
+        minute,
+        second: 0.0,
+        tz_offset_hours: 5.5,
+    })
+}
+
+#[test]
+fn sankranti_instants_match_published_times() {
+    // Drik Panchang (Lahiri): Makara 15 January 02:54 IST, Mesha
+    // 13 April 21:15 IST. The margin covers the few arcseconds
+    // between Lahiri variants; a J2000 Sun is about 8 hours late.
+    let minutes = |a: f64, b: f64| (a - b).abs() * 1440.0;
+
+    let makara = sankranti(1, 14, 9);
+    assert!(minutes(makara, ist(1, 15, 2, 54)) < 12.0);
+
+    let mesha = sankranti(4, 13, 0);
+    assert!(minutes(mesha, ist(4, 13, 21, 15)) < 12.0);
+}
+
+#[test]
+fn polar_night_uses_the_civil_day() {
+    let days = daily_panchang(
+        2024,
+        12,
+        20,
+        2,
+        1.0,
+        80f64.to_radians(),
+        15f64.to_radians(),
+        &COMPAT,
+    );
+
+    for d in &days {
+        assert!(d.sunrise.is_none() && d.sunset.is_none());
+        assert!(d.vara.is_none());
+        assert_covers(&d.tithis, d.midnight_jd, d.midnight_jd + 1.0);
+    }
+}
@@ -0,0 +1,149 @@
+use kundli_core::PlanetJS;
+use kundli_core::dasha::drig::drig_sequence;
+use kundli_core::dasha::rashi::{RashiChart, sthira_years};
+use kundli_core::dasha::shoola::{niryana_shoola_dasha, shoola_dasha};
+use kundli_core::dasha::sthira::{brahma, sthira_dasha};
+use kundli_core::dasha::vimshottari::DashaLord;
+use kundli_core::dasha::year::{DashaClock, DashaYear};
+
+const BIRTH: f64 = 2451545.0;
+const YEAR: f64 = 365.25;
+
+fn planet(name: &str, sidereal_deg: f64) -> PlanetJS {
+    PlanetJS {
+        name: name.to_string(),
+        tropical_deg: sidereal_deg,
+        sidereal_deg,
+    }
+}
+
+fn rashi_chart(asc: f64, planets: &[(&str, f64)]) -> RashiChart {
+    let planets: Vec<PlanetJS> = planets.iter().map(|&(n, l)| planet(n, l)).collect();
+    RashiChart::new(asc, &planets)
+}
+
+fn clock() -> DashaClock {
+    DashaClock::new(BIRTH, DashaYear::Julian)
+}
+
+fn signs_and_years(periods: &[kundli_core::dasha::rashi::RashiPeriod]) -> Vec<(u8, f64)> {
+    periods.iter().map(|p| (p.sign, p.years)).collect()
+}
+
+#[test]
+fn sthira_years_by_sign_kind() {
+    let years: Vec<f64> = (0..12).map(sthira_years).collect();
+    assert_eq!(
+        years,
+        [7.0, 8.0, 9.0, 7.0, 8.0, 9.0, 7.0, 8.0, 9.0, 7.0, 8.0, 9.0]
+    );
+    assert_eq!(years.iter().sum::<f64>(), 96.0);
+}
+
+#[test]
+fn sthira_starts_from_brahma() {
+    // Sun makes Aries the base. Mercury (lord of the 6th) is in odd
+    // Aquarius, the 11th; Mars (8th) in odd Leo, only the 5th.
+    let chart = rashi_chart(
+        5.0,
+        &[
+            ("Sun", 10.0),
+            ("Mars", 125.0),
+            ("Mercury", 305.0),
+            ("Jupiter", 40.0),
+        ],
+    );
+    assert_eq!(brahma(&chart), Some(DashaLord::Mercury));
+
+    let dashas = sthira_dasha(&clock(), &chart);
+    assert_eq!(
+        signs_and_years(&dashas[..4]),
+        [(10, 8.0), (11, 9.0), (0, 7.0), (1, 8.0)]
+    );
+
+    let days = dashas[11].end_jd - dashas[0].start_jd;
+    assert!((days - 96.0 * YEAR).abs() < 1e-6);
+}
+
+#[test]
+fn sthira_brahma_falls_back_to_lord_of_eighth() {
+    // No lord of the 6th, 8th or 12th in an odd sign
+    let chart = rashi_chart(
+        5.0,
+        &[
+            ("Sun", 10.0),
+            ("Mars", 40.0),
+            ("Mercury", 100.0),
+            ("Jupiter", 280.0),
+        ],
+    );
+    assert_eq!(brahma(&chart), Some(DashaLord::Mars));
+
+    // Taurus is even: reverse order
+    let dashas = sthira_dasha(&clock(), &chart);
+    assert_eq!(
+        signs_and_years(&dashas[..3]),
+        [(1, 8.0), (0, 7.0), (11, 9.0)]
+    );
+}
+
+#[test]
+fn shoola_runs_nine_years_per_sign() {
+    // Libra (7th) holds a planet, Aries is empty
+    let chart = rashi_chart(5.0, &[("Moon", 190.0)]);
+    let dashas = shoola_dasha(&clock(), &chart);
+
+    let signs: Vec<u8> = dashas.iter().map(|d| d.sign).collect();
+    assert_eq!(signs, [6, 7, 8, 9, 10, 11, 0, 1, 2, 3, 4, 5]);
+
+    for d in &dashas {
+        assert_eq!(d.years, 9.0);
+        assert!((d.end_jd - d.start_jd - 9.0 * YEAR).abs() < 1e-6);
+    }
+    assert!((dashas[11].end_jd - (BIRTH + 108.0 * YEAR)).abs() < 1e-6);
+}
+
+#[test]
+fn niryana_shoola_starts_from_second_or_eighth() {
+    // Scorpio (8th) is stronger; lagna and 7th play no part
+    let chart = rashi_chart(5.0, &[("Sun", 10.0), ("Moon", 190.0), ("Mars", 220.0)]);
+    let dashas = niryana_shoola_dasha(&clock(), &chart);
+    assert_eq!(
+        signs_and_years(&dashas[..4]),
+        [(7, 8.0), (6, 7.0), (5, 9.0), (4, 8.0)]
+    );
+
+    // Taurus (2nd) is stronger
+    let chart = rashi_chart(5.0, &[("Sun", 10.0), ("Mars", 40.0)]);
+    let dashas = niryana_shoola_dasha(&clock(), &chart);
+    assert_eq!(
+        signs_and_years(&dashas[..3]),
+        [(1, 8.0), (0, 7.0), (11, 9.0)]
+    );
+
+    // Gemini lagna: Cancer (2nd) against Capricorn (8th)
+    let chart = rashi_chart(65.0, &[("Saturn", 280.0)]);
+    assert_eq!(niryana_shoola_dasha(&clock(), &chart)[0].sign, 9);
+}
+
+#[test]
+fn drig_order_of_the_signs() {
+    // Aries lagna: Sagittarius (odd, forward) with the duals it
+    // aspects, Capricorn (even, reverse) with Scorpio, Leo, Taurus,
+    // Aquarius (odd, forward) with Aries, Cancer, Libra
+    assert_eq!(drig_sequence(0), [8, 11, 2, 5, 9, 7, 4, 1, 10, 0, 3, 6]);
+
+    // Gemini lagna: Aquarius and Aries aspect each other and take
+    // Capricorn and Taurus in each other's place
+    assert_eq!(drig_sequence(2), [10, 3, 6, 9, 11, 8, 5, 2, 0, 1, 4, 7]);
+
+    for lagna in 0..12 {
+        let mut signs = drig_sequence(lagna);
+        assert_eq!(signs[0], (lagna + 8) % 12);
+        assert_eq!(signs[4], (lagna + 9) % 12);
+        assert_eq!(signs[8], (lagna + 10) % 12);
+
+        signs.sort();
+        assert_eq!(signs, (0..12).collect::<Vec<u8>>(), "lagna {lagna}");
+    }
+}
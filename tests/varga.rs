@@ -0,0 +1,245 @@
+use kundli_core::divisional::{VargaScheme, varga_position};
+
+// Leo (120°–150°) is an odd fixed fire sign, Pisces (330°–360°)
+// an even dual water sign. Each case is the start of the first
+// amsa, both sides of the first boundary and the last amsa.
+//
+// Sign indices: 0 Aries, 1 Taurus, 2 Gemini, 3 Cancer, 4 Leo,
+// 5 Virgo, 6 Libra, 7 Scorpio, 8 Sagittarius, 9 Capricorn,
+// 10 Aquarius, 11 Pisces.
+const CASES: [(u8, [(f64, u8); 4], [(f64, u8); 4]); 14] = [
+    // Hora: odd Sun then Moon, even Moon then Sun
+    (
+        2,
+        [(120.5, 4), (134.99, 4), (135.01, 3), (149.99, 3)],
+        [(330.5, 3), (344.99, 3), (345.01, 4), (359.99, 4)],
+    ),
+    // Drekkana: 1st, 5th, 9th
+    (
+        3,
+        [(120.5, 4), (129.99, 4), (130.01, 8), (149.99, 0)],
+        [(330.5, 11), (339.99, 11), (340.01, 3), (359.99, 7)],
+    ),
+    // Chaturthamsa: 1st, 4th, 7th, 10th
+    (
+        4,
+        [(120.5, 4), (127.49, 4), (127.51, 7), (149.99, 1)],
+        [(330.5, 11), (337.49, 11), (337.51, 2), (359.99, 8)],
+    ),
+    // Saptamsa: odd from itself, even from the 7th
+    (
+        7,
+        [(120.5, 4), (124.28, 4), (124.29, 5), (149.99, 10)],
+        [(330.5, 5), (334.28, 5), (334.29, 6), (359.99, 11)],
+    ),
+    // Navamsa: fire from Aries, water from Cancer
+    (
+        9,
+        [(120.5, 0), (123.33, 0), (123.34, 1), (149.99, 8)],
+        [(330.5, 3), (333.33, 3), (333.34, 4), (359.99, 11)],
+    ),
+    // Dasamsa: odd from itself, even from the 9th
+    (
+        10,
+        [(120.5, 4), (122.99, 4), (123.01, 5), (149.99, 1)],
+        [(330.5, 7), (332.99, 7), (333.01, 8), (359.99, 4)],
+    ),
+    // Dwadasamsa: from itself
+    (
+        12,
+        [(120.5, 4), (122.49, 4), (122.51, 5), (149.99, 3)],
+        [(330.5, 11), (332.49, 11), (332.51, 0), (359.99, 10)],
+    ),
+    // Shodasamsa: fixed from Leo, dual from Sagittarius
+    (
+        16,
+        [(120.5, 4), (121.87, 4), (121.88, 5), (149.99, 7)],
+        [(330.5, 8), (331.87, 8), (331.88, 9), (359.99, 11)],
+    ),
+    // Vimsamsa: fixed from Sagittarius, dual from Leo
+    (
+        20,
+        [(120.5, 8), (121.49, 8), (121.51, 9), (149.99, 3)],
+        [(330.5, 4), (331.49, 4), (331.51, 5), (359.99, 11)],
+    ),
+    // Siddhamsa: odd from Leo, even from Cancer
+    (
+        24,
+        [(120.5, 4), (121.24, 4), (121.26, 5), (149.99, 3)],
+        [(330.5, 3), (331.24, 3), (331.26, 4), (359.99, 2)],
+    ),
+    // Bhamsa: fire from Aries, water from Capricorn
+    (
+        27,
+        [(120.5, 0), (121.11, 0), (121.12, 1), (149.99, 2)],
+        [(330.5, 9), (331.11, 9), (331.12, 10), (359.99, 11)],
+    ),
+    // Khavedamsa: odd from Aries, even from Libra
+    (
+        40,
+        [(120.5, 0), (120.74, 0), (120.76, 1), (149.99, 3)],
+        [(330.5, 6), (330.74, 6), (330.76, 7), (359.99, 9)],
+    ),
+    // Akshavedamsa: fixed from Leo, dual from Sagittarius
+    (
+        45,
+        [(120.5, 4), (120.66, 4), (120.67, 5), (149.99, 0)],
+        [(330.5, 8), (330.66, 8), (330.67, 9), (359.99, 4)],
+    ),
+    // Shashtiamsa: from itself
+    (
+        60,
+        [(120.2, 4), (120.49, 4), (120.51, 5), (149.99, 3)],
+        [(330.2, 11), (330.49, 11), (330.51, 0), (359.99, 10)],
+    ),
+];
+
+fn sign(division: u8, lon: f64) -> u8 {
+    varga_position(division, lon, VargaScheme::Parashari).0
+}
+
+#[test]
+fn parashari_boundaries_in_odd_and_even_signs() {
+    for (division, odd, even) in CASES {
+        for (lon, expected) in odd.into_iter().chain(even) {
+            assert_eq!(sign(division, lon), expected, "D{division} at {lon}°");
+        }
+    }
+}
+
+#[test]
+fn amsa_offset_restarts_at_each_boundary() {
+    for (division, odd, _) in CASES {
+        let part = 30.0 / division as f64;
+
+        // Just past the first boundary the offset is close to zero
+        let (_, offset) = varga_position(division, 120.0 + part + 1e-6, VargaScheme::Parashari);
+        assert!(offset < 1e-5, "D{division}: offset {offset}");
+
+        let (_, offset) = varga_position(division, odd[3].0, VargaScheme::Parashari);
+        assert!(offset < part, "D{division}: offset {offset}");
+    }
+}
+
+#[test]
+fn trimsamsa_odd_sign_segments() {
+    // Leo: Mars 0–5 (Aries), Saturn 5–10 (Aquarius), Jupiter 10–18
+    // (Sagittarius), Mercury 18–25 (Gemini), Venus 25–30 (Libra)
+    for (lon, expected) in [
+        (120.0, 0),
+        (124.99, 0),
+        (125.0, 10),
+        (129.99, 10),
+        (130.0, 8),
+        (137.99, 8),
+        (138.0, 2),
+        (144.99, 2),
+        (145.0, 6),
+        (149.99, 6),
+    ] {
+        assert_eq!(sign(30, lon), expected, "{lon}°");
+    }
+}
+
+#[test]
+fn trimsamsa_even_sign_segments_are_reversed() {
+    // Pisces: Venus 0–5 (Taurus), Mercury 5–12 (Virgo), Jupiter
+    // 12–20 (Pisces), Saturn 20–25 (Capricorn), Mars 25–30 (Scorpio)
+    for (lon, expected) in [
+        (330.0, 1),
+        (334.99, 1),
+        (335.0, 5),
+        (341.99, 5),
+        (342.0, 11),
+        (349.99, 11),
+        (350.0, 9),
+        (354.99, 9),
+        (355.0, 7),
+        (359.99, 7),
+    ] {
+        assert_eq!(sign(30, lon), expected, "{lon}°");
+    }
+}
+
+#[test]
+fn trimsamsa_offsets_run_forward_in_both_signs() {
+    // 2° into the Saturn segment of Leo
+    let (_, offset) = varga_position(30, 127.0, VargaScheme::Parashari);
+    assert!((offset - 2.0).abs() < 1e-9);
+
+    // 1° into the Mercury segment of Pisces (5°–12°)
+    let (_, offset) = varga_position(30, 336.0, VargaScheme::Parashari);
+    assert!((offset - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn cyclic_count_wraps_in_late_signs() {
+    // Pisces, last amsa of D23: 11 × 23 + 22 = 275, Pisces
+    assert_eq!(sign(23, 359.99), 11);
+
+    // Pisces, first amsa of D22: 11 × 22 = 242, Gemini
+    assert_eq!(sign(22, 330.5), 2);
+}
+
+#[test]
+fn high_divisions_count_cyclically_from_leo_to_pisces() {
+    // (first amsa, last amsa) for Leo .. Pisces: sign × n + amsa,
+    // taken modulo 12. Leo in D81 starts at 4 × 81 = 324, Aries.
+    let cases: [(u8, [(u8, u8); 8]); 4] = [
+        (
+            81,
+            [
+                (0, 8),
+                (9, 5),
+                (6, 2),
+                (3, 11),
+                (0, 8),
+                (9, 5),
+                (6, 2),
+                (3, 11),
+            ],
+        ),
+        (108, [(0, 11); 8]),
+        (144, [(0, 11); 8]),
+        (
+            150,
+            [
+                (0, 5),
+                (6, 11),
+                (0, 5),
+                (6, 11),
+                (0, 5),
+                (6, 11),
+                (0, 5),
+                (6, 11),
+            ],
+        ),
+    ];
+
+    for (division, signs) in cases {
+        for (i, (first, last)) in signs.into_iter().enumerate() {
+            let start = (4 + i) as f64 * 30.0;
+            assert_eq!(
+                sign(division, start + 0.001),
+                first,
+                "D{division} at {start}°"
+            );
+            assert_eq!(
+                sign(division, start + 29.999),
+                last,
+                "D{division} at {start}°"
+            );
+        }
+    }
+}
+
+#[test]
+fn parivritti_in_pisces_at_large_divisions() {
+    // (first amsa, last amsa) of Pisces: 11 × n and 11 × n + n − 1,
+    // taken modulo 12
+    for (division, first, last) in [(30, 6, 11), (40, 8, 11), (45, 3, 11), (60, 0, 11)] {
+        let parivritti = |lon| varga_position(division, lon, VargaScheme::Parivritti).0;
+        assert_eq!(parivritti(330.001), first, "D{division}");
+        assert_eq!(parivritti(359.999), last, "D{division}");
+    }
+}
@@ -0,0 +1,100 @@
+use kundli_core::PlanetJS;
+use kundli_core::astrology::upagraha::aprakasha_grahas;
+use kundli_core::divisional::{DivisionalChart, VargaScheme, divisional_chart_with_lagna};
+
+fn planet(name: &str, sidereal_deg: f64) -> PlanetJS {
+    PlanetJS {
+        name: name.to_string(),
+        tropical_deg: sidereal_deg,
+        sidereal_deg,
+    }
+}
+
+/// Whole-sign house of a varga sign, counted from the varga lagna
+fn house_of(chart: &DivisionalChart, sign: &str) -> u8 {
+    chart
+        .houses
+        .iter()
+        .find(|h| h.sign.name() == sign)
+        .map(|h| h.number)
+        .unwrap()
+}
+
+fn navamsa() -> DivisionalChart {
+    let planets = [planet("Sun", 11.0), planet("Moon", 200.0)];
+    let special: Vec<PlanetJS> = aprakasha_grahas(11.0)
+        .into_iter()
+        .map(|(name, lon)| planet(name, lon))
+        .collect();
+
+    // Lagna 11° Cancer
+    divisional_chart_with_lagna(9, &planets, 101.0, &special, VargaScheme::Parashari)
+}
+
+#[test]
+fn navamsa_lagna_and_houses() {
+    let chart = navamsa();
+
+    // 4th navamsa of a water sign (counted from Cancer): Libra
+    let lagna = chart.ascendant.as_ref().unwrap();
+    assert_eq!(lagna.sign, "Libra");
+    assert_eq!(lagna.amsha.index, 4);
+    assert!((lagna.degree - (11.0 - 10.0)).abs() < 1e-9);
+
+    let signs: Vec<&str> = chart.houses.iter().map(|h| h.sign.name()).collect();
+    assert_eq!(
+        signs,
+        [
+            "Libra",
+            "Scorpio",
+            "Sagittarius",
+            "Capricorn",
+            "Aquarius",
+            "Pisces",
+            "Aries",
+            "Taurus",
+            "Gemini",
+            "Cancer",
+            "Leo",
+            "Virgo"
+        ]
+    );
+    assert!(chart.houses.iter().map(|h| h.number).eq(1..=12));
+}
+
+#[test]
+fn navamsa_planets_take_houses_from_the_varga_lagna() {
+    let chart = navamsa();
+
+    // (planet, navamsa sign, house from Libra)
+    for (name, sign, house) in [
+        ("Sun", "Cancer", 10), // 11° Aries
+        ("Moon", "Aries", 7),  // 20° Libra
+    ] {
+        let p = chart.planets.iter().find(|p| p.planet == name).unwrap();
+        assert_eq!(p.sign, sign, "{name}");
+        assert_eq!(house_of(&chart, &p.sign), house, "{name}");
+    }
+}
+
+#[test]
+fn navamsa_special_points() {
+    let chart = navamsa();
+
+    // Sun at 11° Aries: Dhuma 144°20′, Vyatipata 215°40′,
+    // Parivesha 35°40′, Indrachapa 324°20′, Upaketu 341°
+    let expected = [
+        ("Dhuma", "Scorpio", 2),
+        ("Vyatipata", "Leo", 11),
+        ("Parivesha", "Aquarius", 5),
+        ("Indrachapa", "Taurus", 8),
+        ("Upaketu", "Libra", 1),
+    ];
+
+    assert_eq!(chart.special_points.len(), expected.len());
+    for (p, (name, sign, house)) in chart.special_points.iter().zip(expected) {
+        assert_eq!(p.planet, name);
+        assert_eq!(p.sign, sign, "{name}");
+        assert_eq!(house_of(&chart, &p.sign), house, "{name}");
+    }
+}
@@ -0,0 +1,108 @@
+use kundli_core::PlanetJS;
+use kundli_core::astrology::dignity::Dignity;
+use kundli_core::dasha::vimshottari::DashaLord;
+use kundli_core::divisional::{VargaGroup, VargaSchemes, register_varga_table, vimshopaka_bala};
+
+fn planet(name: &str, sidereal_deg: f64) -> PlanetJS {
+    PlanetJS {
+        name: name.to_string(),
+        tropical_deg: sidereal_deg,
+        sidereal_deg,
+    }
+}
+
+/// Schemes sending every amsa of every division to Aries
+fn all_aries() -> VargaSchemes {
+    let mut schemes = VargaSchemes::default();
+
+    for &(division, _) in VargaGroup::Shodashavarga.weights() {
+        let signs = vec![0; 12 * division as usize];
+        let scheme = register_varga_table(&format!("AllAries{division}"), division, signs).unwrap();
+        schemes.set(division, scheme);
+    }
+
+    schemes
+}
+
+#[test]
+fn exalted_in_every_varga_scores_twenty() {
+    let schemes = all_aries();
+    let planets = [planet("Sun", 10.5)];
+
+    for (group, amsa) in [
+        (VargaGroup::Shadvarga, None),
+        (VargaGroup::Saptavarga, Some("Mukuta")),
+        (VargaGroup::Dashavarga, Some("Sridhama")),
+        (VargaGroup::Shodashavarga, Some("Srivallabha")),
+    ] {
+        let sun = &vimshopaka_bala(&planets, group, &schemes)[0];
+
+        assert_eq!(sun.planet, DashaLord::Sun);
+        assert!((sun.points - 20.0).abs() < 1e-9, "{}", group.name());
+        assert!(sun.vargas.iter().all(|v| v.dignity == Dignity::Exalted));
+        assert_eq!(sun.good_vargas as usize, group.weights().len());
+        assert_eq!(sun.amsa, amsa, "{}", group.name());
+        assert!(sun.vargottama);
+    }
+}
+
+#[test]
+fn group_weights_sum_to_twenty() {
+    for group in [
+        VargaGroup::Shadvarga,
+        VargaGroup::Saptavarga,
+        VargaGroup::Dashavarga,
+        VargaGroup::Shodashavarga,
+    ] {
+        let total: f64 = group.weights().iter().map(|(_, w)| w).sum();
+        assert!((total - 20.0).abs() < 1e-9, "{}", group.name());
+    }
+}
+
+#[test]
+fn mixed_shadvarga() {
+    // Sun 10°30′ Aries, Moon 14° Taurus (2nd from the Sun, so
+    // temporary friends); Mercury and Jupiter are not in the chart
+    let planets = [planet("Sun", 10.5), planet("Moon", 44.0)];
+    let bala = vimshopaka_bala(&planets, VargaGroup::Shadvarga, &VargaSchemes::default());
+
+    let dignities = |i: usize| -> Vec<(u8, Dignity, f64)> {
+        bala[i]
+            .vargas
+            .iter()
+            .map(|v| (v.division, v.dignity, v.points))
+            .collect()
+    };
+
+    // Sun: Aries, Leo, Leo, Cancer, Leo, Sagittarius
+    assert_eq!(
+        dignities(0),
+        [
+            (1, Dignity::Exalted, 20.0),
+            (2, Dignity::Moolatrikona, 20.0),
+            (3, Dignity::Moolatrikona, 20.0),
+            (9, Dignity::GreatFriend, 18.0),
+            (12, Dignity::Moolatrikona, 20.0),
+            (30, Dignity::Neutral, 10.0),
+        ]
+    );
+    assert!((bala[0].points - 19.0).abs() < 1e-9);
+    assert_eq!(bala[0].good_vargas, 4);
+    assert!(!bala[0].vargottama);
+
+    // Moon: Taurus, Cancer, Virgo, Taurus, Sagittarius, Pisces
+    assert_eq!(
+        dignities(1),
+        [
+            (1, Dignity::Exalted, 20.0),
+            (2, Dignity::Own, 20.0),
+            (3, Dignity::Neutral, 10.0),
+            (9, Dignity::Exalted, 20.0),
+            (12, Dignity::Enemy, 7.0),
+            (30, Dignity::Enemy, 7.0),
+        ]
+    );
+    assert!((bala[1].points - 16.05).abs() < 1e-9);
+    assert_eq!(bala[1].good_vargas, 3);
+    assert!(bala[1].vargottama);
+}
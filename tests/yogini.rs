@@ -0,0 +1,97 @@
+use kundli_core::dasha::year::{DashaClock, DashaYear};
+use kundli_core::dasha::yogini::{
+    Yogini, starting_yogini, yogini_antardasha_timeline, yogini_mahadasha_timeline,
+};
+
+const BIRTH: f64 = 2451545.0;
+const YEAR: f64 = 365.25;
+const NAK_LEN: f64 = 360.0 / 27.0;
+
+fn clock() -> DashaClock {
+    DashaClock::new(BIRTH, DashaYear::Julian)
+}
+
+#[test]
+fn starting_yogini_is_nakshatra_plus_three() {
+    // (nakshatra index from 0, yogini)
+    for (nak, yogini) in [
+        (0, Yogini::Bhramari), // Ashwini: 1 + 3 = 4
+        (3, Yogini::Siddha),   // Rohini: 4 + 3 = 7
+        (4, Yogini::Sankata),  // Mrigashira: 5 + 3 = 8
+        (5, Yogini::Mangala),  // Ardra: 9 → 1
+        (6, Yogini::Pingala),  // Punarvasu: 10 → 2
+        (26, Yogini::Ulka),    // Revati: 30 → 6
+    ] {
+        assert_eq!(starting_yogini(nak), yogini, "nakshatra {nak}");
+    }
+
+    for nak in 0..27 {
+        let number = (nak + 1 + 3) % 8;
+        let expected = if number == 0 { 8 } else { number };
+        assert_eq!(starting_yogini(nak).years() as usize, expected);
+    }
+}
+
+#[test]
+fn each_cycle_lasts_36_years() {
+    let timeline = yogini_mahadasha_timeline(&clock(), 100.0);
+    assert_eq!(timeline.len(), 24);
+
+    let total: f64 = [
+        Yogini::Mangala,
+        Yogini::Pingala,
+        Yogini::Dhanya,
+        Yogini::Bhramari,
+        Yogini::Bhadrika,
+        Yogini::Ulka,
+        Yogini::Siddha,
+        Yogini::Sankata,
+    ]
+    .iter()
+    .map(|y| y.years())
+    .sum();
+    assert_eq!(total, 36.0);
+
+    for cycle in timeline.chunks(8) {
+        let days = cycle[7].end_jd - cycle[0].start_jd;
+        assert!((days - 36.0 * YEAR).abs() < 1e-6);
+    }
+
+    for pair in timeline.windows(2) {
+        assert_eq!(pair[1].yogini, pair[0].yogini.next());
+        assert_eq!(pair[1].start_jd, pair[0].end_jd);
+    }
+}
+
+#[test]
+fn birth_balance() {
+    // Moon a quarter into Ashwini: Bhramari (4 years), 1 year gone
+    let timeline = yogini_mahadasha_timeline(&clock(), NAK_LEN / 4.0);
+    let first = &timeline[0];
+
+    assert_eq!(first.yogini, Yogini::Bhramari);
+    assert!((first.start_jd - (BIRTH - YEAR)).abs() < 1e-6);
+    assert!((first.end_jd - (BIRTH + 3.0 * YEAR)).abs() < 1e-6);
+
+    // Moon at 60% of Rohini: Siddha (7 years), 2.8 years left
+    let timeline = yogini_mahadasha_timeline(&clock(), 3.6 * NAK_LEN);
+    assert_eq!(timeline[0].yogini, Yogini::Siddha);
+    assert!((timeline[0].end_jd - (BIRTH + 2.8 * YEAR)).abs() < 1e-6);
+}
+
+#[test]
+fn antardashas_fill_each_mahadasha() {
+    let maha = yogini_mahadasha_timeline(&clock(), 100.0);
+    let antar = yogini_antardasha_timeline(&clock(), 100.0);
+
+    for (m, parts) in maha.iter().zip(antar.chunks(8)) {
+        assert_eq!(parts[0].antara, m.yogini);
+        assert_eq!(parts[0].start_jd, m.start_jd);
+        assert!((parts[7].end_jd - m.end_jd).abs() < 1e-6);
+
+        // Antara of yogini a lasts maha years × a / 36
+        let first_days = parts[0].end_jd - parts[0].start_jd;
+        let expected = m.yogini.years() * m.yogini.years() / 36.0 * YEAR;
+        assert!((first_days - expected).abs() < 1e-6);
+    }
+}
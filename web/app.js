@@ -1,12 +1,20 @@
-import init, { generate_kundli } from "./pkg/kundli_core.js";
+import init, { generate_kundli_with_options } from "./pkg/kundli_core.js";
 
 await init();
 
 const resultsSection = document.getElementById("results");
 const divisionSelect = document.getElementById("division");
+const dashaSystemSelect = document.getElementById("dasha-system");
+const dashaYearSelect = document.getElementById("dasha-year");
+const dashaSeedSelect = document.getElementById("dasha-seed");
+const compatSelect = document.getElementById("compat");
 
 // Populate divisions
-for (let i = 1; i <= 30; i++) {
+const divisions = Array.from({ length: 30 }, (_, i) => i + 1).concat([
+  40, 45, 60, 81, 108, 144, 150,
+]);
+
+for (const i of divisions) {
   const opt = document.createElement("option");
   opt.value = i;
   opt.textContent = `D${i}`;
@@ -30,7 +38,7 @@ document.getElementById("generate").onclick = () => {
   const lat = +document.getElementById("lat").value;
   const lon = +document.getElementById("lon").value;
 
-  const chart = generate_kundli(
+  const chart = generate_kundli_with_options(
     year,
     month,
     day,
@@ -40,6 +48,13 @@ document.getElementById("generate").onclick = () => {
     tz,
     lat,
     lon,
+    {
+      dasha_system: dashaSystemSelect.value,
+      dasha_year: dashaYearSelect.value || undefined,
+      dasha_seed: dashaSeedSelect.value,
+      compatibility: compatSelect.value,
+      sections: { vargas: true, panchang: true },
+    },
   );
 
   window.lastChart = chart;
@@ -47,6 +62,8 @@ document.getElementById("generate").onclick = () => {
   resultsSection.style.display = "block";
 
   renderHouses(chart);
+  renderBhavas(chart);
+  renderPanchang(chart);
   renderPlanets(chart);
   renderDivisional(chart, +divisionSelect.value);
   renderDashas(chart);
@@ -58,6 +75,19 @@ divisionSelect.onchange = () => {
   }
 };
 
+for (const select of [
+  dashaSystemSelect,
+  dashaYearSelect,
+  dashaSeedSelect,
+  compatSelect,
+]) {
+  select.onchange = () => {
+    if (window.lastChart) {
+      document.getElementById("generate").click();
+    }
+  };
+}
+
 // ---------------- HOUSES ----------------
 
 function renderHouses(chart) {
@@ -75,6 +105,66 @@ function renderHouses(chart) {
   document.getElementById("houses").innerHTML = html;
 }
 
+// ---------------- BHAVA CHALIT ----------------
+
+function renderBhavas(chart) {
+  let html = `
+    <table>
+      <tr><th>Bhava</th><th>Start (°)</th><th>Madhya (°)</th><th>End (°)</th><th>Planets</th></tr>
+  `;
+
+  for (const b of chart.bhavas) {
+    const planets = chart.bhava_chalit
+      .filter((p) => p.bhava === b.number)
+      .map((p) => (p.shifted ? `${p.planet} (from ${p.rashi_house})` : p.planet))
+      .join(", ");
+
+    html += `
+      <tr>
+        <td>${b.number}</td>
+        <td>${b.start_sandhi_deg.toFixed(2)}</td>
+        <td>${b.madhya_deg.toFixed(2)}</td>
+        <td>${b.end_sandhi_deg.toFixed(2)}</td>
+        <td>${planets}</td>
+      </tr>
+    `;
+  }
+
+  html += "</table>";
+  document.getElementById("bhavas").innerHTML = html;
+}
+
+// ---------------- PANCHANG ----------------
+
+function renderPanchang(chart) {
+  const p = chart.panchang;
+  let html = `
+    <table>
+      <tr><th>Limb</th><th>Name</th><th>Start</th><th>End</th></tr>
+  `;
+
+  for (const [label, limb] of [
+    ["Tithi", p.tithi],
+    ["Vara", p.vara],
+    ["Nakshatra", p.nakshatra],
+    ["Yoga", p.yoga],
+    ["Karana", p.karana],
+  ]) {
+    if (!limb) continue;
+    html += `
+      <tr>
+        <td>${label}</td>
+        <td>${limb.name}</td>
+        <td>${limb.start}</td>
+        <td>${limb.end}</td>
+      </tr>
+    `;
+  }
+
+  html += "</table>";
+  document.getElementById("panchang").innerHTML = html;
+}
+
 // ---------------- PLANETS ----------------
 
 function renderPlanets(chart) {
@@ -112,7 +202,9 @@ function renderDivisional(chart, div) {
       <tr><th>Planet</th><th>Sign</th><th>Degree</th></tr>
   `;
 
-  for (const p of d.planets) {
+  const rows = d.ascendant ? [d.ascendant, ...d.planets] : d.planets;
+
+  for (const p of rows) {
     html += `
       <tr>
         <td>${p.planet}</td>
@@ -132,6 +224,12 @@ function renderDashas(chart) {
   const today = new Date().toISOString().slice(0, 10);
   let html = "";
 
+  const bal = chart.dasha_balance;
+  if (bal) {
+    html += `<p><strong>Balance at birth:</strong> ${bal.lord}
+      ${bal.balance.years}y ${bal.balance.months}m ${Math.floor(bal.balance.days)}d</p>`;
+  }
+
   for (const maha of chart.mahadashas) {
     const isCurrentMaha = today >= maha.start_date && today <= maha.end_date;
 
@@ -141,7 +239,10 @@ function renderDashas(chart) {
         (${maha.start_date} → ${maha.end_date})</summary>
     `;
 
-    const antars = chart.antardashas.filter((a) => a.maha === maha.maha);
+    // Yogini repeats every 36 years, so match by time span, not name
+    const antars = chart.antardashas.filter(
+      (a) => a.start_jd >= maha.start_jd - 1e-6 && a.end_jd <= maha.end_jd + 1e-6,
+    );
 
     for (const antar of antars) {
       const isCurrentAntar =
@@ -156,7 +257,8 @@ function renderDashas(chart) {
       `;
 
       const pratys = chart.pratyantardashas.filter(
-        (p) => p.maha === maha.maha && p.antara === antar.antara,
+        (p) =>
+          p.start_jd >= antar.start_jd - 1e-6 && p.end_jd <= antar.end_jd + 1e-6,
       );
 
       for (const p of pratys) {